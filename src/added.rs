@@ -0,0 +1,35 @@
+/// Used to only iterate over components added since the system last ran.
+/// Iterators skip entities whose component wasn't added, the storage has to track changes.
+///
+/// `Added` never drives an iteration, iterating over `Added` storages only yields nothing,
+/// use `View::added` instead.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// let world = World::new::<(usize, u32)>();
+///
+/// world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(|(mut entities, mut usizes, mut u32s)| {
+///     entities.add_entity((&mut usizes, &mut u32s), (0usize, 1u32));
+/// });
+///
+/// world.track_changes::<u32>();
+///
+/// world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(|(mut entities, mut usizes, mut u32s)| {
+///     entities.add_entity((&mut usizes, &mut u32s), (2usize, 3u32));
+/// });
+///
+/// world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+///     let mut iter = (&usizes, Added(&u32s)).iter();
+///     assert_eq!(iter.next(), Some((&2, &3)));
+///     assert_eq!(iter.next(), None);
+/// });
+/// ```
+#[derive(Copy, Clone)]
+pub struct Added<T>(pub T);
+
+impl<T> Added<T> {
+    /// Returns the usual `T` storage.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
@@ -0,0 +1,36 @@
+/// Used to iterate over entities having at least one of multiple components.
+/// Each component is yielded as an `Option`, `None` when the entity doesn't have it.
+///
+/// Entities are yielded once, even when they have more than one of the components.\
+/// `AnyOf` is made of views only, filters like `Not` and `AnyOf` itself can't be nested inside.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// struct Player(u32);
+/// struct Npc(u32);
+///
+/// let world = World::new::<(Player, Npc)>();
+///
+/// world.run::<(EntitiesMut, &mut Player, &mut Npc), _, _>(|(mut entities, mut players, mut npcs)| {
+///     entities.add_entity(&mut players, Player(0));
+///     entities.add_entity(&mut npcs, Npc(1));
+///     entities.add_entity((&mut players, &mut npcs), (Player(2), Npc(2)));
+/// });
+///
+/// world.run::<(&Player, &Npc), _, _>(|(players, npcs)| {
+///     let mut ids = Vec::new();
+///     AnyOf((&players, &npcs)).iter().for_each(|(player, npc)| {
+///         ids.push((player.map(|player| player.0), npc.map(|npc| npc.0)));
+///     });
+///     assert_eq!(ids, vec![(Some(0), None), (Some(2), Some(2)), (None, Some(1))]);
+/// });
+/// ```
+#[derive(Copy, Clone)]
+pub struct AnyOf<T>(pub T);
+
+impl<T> AnyOf<T> {
+    /// Returns the usual storages.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
@@ -42,6 +42,12 @@ impl<T> AtomicRefCell<T> {
             borrow: self.borrow_state.try_borrow_mut()?,
         })
     }
+    /// Returns a mutable reference to the wrapped value.
+    ///
+    /// No runtime check is needed since `&mut self` guarantees no borrow is active.
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.inner.get() }
+    }
 }
 
 /// `BorrowState` keeps track of which borrow is currently active.
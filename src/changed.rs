@@ -0,0 +1,40 @@
+/// Used to only iterate over components added or modified since the system last ran.
+/// Iterators skip entities whose component didn't change, the storage has to track changes.
+///
+/// `Changed` never drives an iteration, iterating over `Changed` storages only yields nothing,
+/// use `View::changed` instead.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// let world = World::new::<(usize, u32)>();
+///
+/// let (entity0, _) = world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+///     |(mut entities, mut usizes, mut u32s)| {
+///         (
+///             entities.add_entity((&mut usizes, &mut u32s), (0usize, 1u32)),
+///             entities.add_entity((&mut usizes, &mut u32s), (2usize, 3u32)),
+///         )
+///     },
+/// );
+///
+/// world.track_changes::<u32>();
+///
+/// world.run::<&mut u32, _, _>(|mut u32s| {
+///     *(&mut u32s).get(entity0).unwrap() += 1;
+/// });
+///
+/// world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+///     let mut iter = (&usizes, Changed(&u32s)).iter();
+///     assert_eq!(iter.next(), Some((&0, &2)));
+///     assert_eq!(iter.next(), None);
+/// });
+/// ```
+#[derive(Copy, Clone)]
+pub struct Changed<T>(pub T);
+
+impl<T> Changed<T> {
+    /// Returns the usual `T` storage.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
@@ -0,0 +1,170 @@
+use crate::error;
+use crate::remove::Remove;
+use crate::storage::{AllStorages, Entities, EntitiesView, EntityId};
+use crate::world::World;
+use std::sync::Mutex;
+
+type Command = Box<dyn FnOnce(&World) -> Result<(), error::ApplyCommands> + Send>;
+
+/// Type used to borrow the command buffer.
+///
+/// Commands let systems add and delete entities or components without borrowing
+/// `EntitiesMut` or `AllStorages`, systems using them can still run in parallel.\
+/// The commands are recorded and applied after the batch of systems they were recorded in
+/// or when calling [World::apply_commands].
+///
+/// Commands recorded by systems of the same batch are applied in no particular order.\
+/// `Commands` borrows `Entities` immutably to reserve the entities it adds.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// struct Spawner;
+/// impl<'a> System<'a> for Spawner {
+///     type Data = (Commands, &'a u32);
+///     fn run((mut commands, u32s): <Self::Data as SystemData>::View) {
+///         u32s.iter().for_each(|&x| {
+///             commands.add_entity((x as usize,));
+///         });
+///     }
+/// }
+///
+/// let world = World::new::<(usize, u32)>();
+/// world.run::<(EntitiesMut, &mut u32), _, _>(|(mut entities, mut u32s)| {
+///     entities.add_entity((&mut u32s,), (0,));
+///     entities.add_entity((&mut u32s,), (1,));
+/// });
+///
+/// world.add_workload("Spawn", Spawner);
+/// world.run_default();
+///
+/// world.run::<&usize, _, _>(|usizes| {
+///     assert_eq!(usizes.len(), 2);
+/// });
+/// ```
+/// [World::apply_commands]: struct.World.html#method.apply_commands
+pub struct Commands;
+
+/// Commands waiting to be applied.
+///
+/// Stored as a unique storage inside `AllStorages`.
+#[derive(Default)]
+pub(crate) struct CommandQueue(Mutex<Vec<Command>>);
+
+impl CommandQueue {
+    /// Removes all recorded commands.
+    pub(crate) fn take(&self) -> Vec<Command> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+    /// Records `command`, it will be applied at the next sync point.
+    pub(crate) fn push(&self, command: Command) {
+        self.0.lock().unwrap().push(command);
+    }
+}
+
+/// Records commands to apply them later.
+///
+/// Commands are moved to the `World` when the view is dropped.
+pub struct CommandsView<'a> {
+    queue: &'a CommandQueue,
+    entities: EntitiesView<'a>,
+    commands: Vec<Command>,
+}
+
+impl<'a> CommandsView<'a> {
+    pub(crate) fn new(queue: &'a CommandQueue, entities: EntitiesView<'a>) -> Self {
+        CommandsView {
+            queue,
+            entities,
+            commands: Vec::new(),
+        }
+    }
+    /// Reserves an entity right away and records the addition of `components` to it.
+    ///
+    /// The returned `EntityId` can be used by other commands straight away.
+    ///
+    /// `T` has to be a tuple even for a single type.
+    /// In this case use (T,).
+    pub fn add_entity<T: CommandComponents>(&mut self, components: T) -> EntityId {
+        let entity = self.entities.reserve_entity();
+        self.add_component(components, entity);
+        entity
+    }
+    /// Records the addition of `components` to `entity`.
+    ///
+    /// If `entity` isn't alive when the command is applied, nothing happens.
+    ///
+    /// `T` has to be a tuple even for a single type.
+    /// In this case use (T,).
+    pub fn add_component<T: CommandComponents>(&mut self, components: T, entity: EntityId) {
+        self.commands.push(Box::new(move |world| {
+            components.add_component(world, entity)
+        }));
+    }
+    /// Records the removal of `T` components from `entity`.
+    ///
+    /// When using packed storages, all storages packed with `T` have to be part of `T`.
+    ///
+    /// `T` has to be a tuple even for a single type.
+    /// In this case use (T,).
+    pub fn remove<T: CommandComponents>(&mut self, entity: EntityId) {
+        self.commands
+            .push(Box::new(move |world| T::remove(world, entity)));
+    }
+    /// Records the deletion of `entity` and all its components.
+    pub fn delete(&mut self, entity: EntityId) {
+        self.commands.push(Box::new(move |world| {
+            world.try_run::<AllStorages, _, _>(|mut all_storages| {
+                all_storages.delete(entity);
+            })?;
+            Ok(())
+        }));
+    }
+}
+
+impl Drop for CommandsView<'_> {
+    fn drop(&mut self) {
+        if !self.commands.is_empty() {
+            self.queue.0.lock().unwrap().append(&mut self.commands);
+        }
+    }
+}
+
+/// Components that can be added or removed through commands.
+pub trait CommandComponents: Send + 'static {
+    fn add_component(self, world: &World, entity: EntityId) -> Result<(), error::ApplyCommands>;
+    fn remove(world: &World, entity: EntityId) -> Result<(), error::ApplyCommands>;
+}
+
+macro_rules! impl_command_components {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: 'static + Send + Sync),+> CommandComponents for ($($type,)+) {
+            fn add_component(self, world: &World, entity: EntityId) -> Result<(), error::ApplyCommands> {
+                world.try_run::<(Entities, ($(&mut $type,)+)), _, _>(|(entities, mut storages)| {
+                    match entities.try_add_component(($(&mut storages.$index,)+), self, entity) {
+                        Ok(()) | Err(error::AddComponent::EntityIsNotAlive) => Ok(()),
+                        Err(err) => Err(err.into()),
+                    }
+                })?
+            }
+            fn remove(world: &World, entity: EntityId) -> Result<(), error::ApplyCommands> {
+                world.try_run::<($(&mut $type,)+), _, _>(|mut storages| {
+                    Remove::<($($type,)+)>::try_remove(($(&mut storages.$index,)+), entity)
+                        .map(|_| ())
+                        .map_err(Into::into)
+                })?
+            }
+        }
+    }
+}
+
+macro_rules! command_components {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_command_components![$(($type, $index))*];
+        command_components![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_command_components![$(($type, $index))*];
+    }
+}
+
+command_components![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
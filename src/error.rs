@@ -1,6 +1,7 @@
 use std::any::TypeId;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
 
 /// AtomicRefCell's borrow error.
 ///
@@ -144,6 +145,84 @@ impl Display for AddComponent {
     }
 }
 
+/// Error returned when recorded commands can't be applied.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ApplyCommands {
+    GetStorage(GetStorage),
+    AddComponent(AddComponent),
+    Remove(Remove),
+}
+
+impl Error for ApplyCommands {}
+
+impl From<GetStorage> for ApplyCommands {
+    fn from(get_storage: GetStorage) -> Self {
+        ApplyCommands::GetStorage(get_storage)
+    }
+}
+
+impl From<AddComponent> for ApplyCommands {
+    fn from(add_component: AddComponent) -> Self {
+        ApplyCommands::AddComponent(add_component)
+    }
+}
+
+impl From<Remove> for ApplyCommands {
+    fn from(remove: Remove) -> Self {
+        ApplyCommands::Remove(remove)
+    }
+}
+
+impl Debug for ApplyCommands {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            ApplyCommands::GetStorage(get_storage) => Debug::fmt(get_storage, fmt),
+            ApplyCommands::AddComponent(add_component) => Debug::fmt(add_component, fmt),
+            ApplyCommands::Remove(remove) => Debug::fmt(remove, fmt),
+        }
+    }
+}
+
+impl Display for ApplyCommands {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
+/// Error returned when an `EntityBuilder` can't build its entity.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BuildEntity {
+    GetStorage(GetStorage),
+    // type name of the component
+    DuplicateComponent(&'static str),
+}
+
+impl Error for BuildEntity {}
+
+impl From<GetStorage> for BuildEntity {
+    fn from(get_storage: GetStorage) -> Self {
+        BuildEntity::GetStorage(get_storage)
+    }
+}
+
+impl Debug for BuildEntity {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            BuildEntity::GetStorage(get_storage) => Debug::fmt(get_storage, fmt),
+            BuildEntity::DuplicateComponent(name) => fmt.write_fmt(format_args!(
+                "{} was added more than once, an entity can only have one component of each type.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for BuildEntity {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
 /// Error occuring when a pack can't be made.
 /// It could be a borrow issue or one of the storage could already have
 /// an incompatible pack or the storage could be unique.
@@ -256,13 +335,39 @@ impl Display for SetDefaultWorkload {
     }
 }
 
-/// Try to run a non existant workload.
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// Try to run a non existant workload or a system of the workload failed.
+///
+/// `System` contains the name of the failing system and its error.
+/// When dispatch fails the error is a [GetStorage].\
+/// Two `System` errors are equal when their system and the displayed error are the same.
+///
+/// [GetStorage]: enum.GetStorage.html
+#[derive(Clone)]
 pub enum RunWorkload {
     Borrow(Borrow),
     MissingWorkload,
+    System((&'static str, Arc<dyn Error + Send + Sync>)),
+    ApplyCommands(ApplyCommands),
 }
 
+impl PartialEq for RunWorkload {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RunWorkload::Borrow(borrow), RunWorkload::Borrow(other)) => borrow == other,
+            (RunWorkload::MissingWorkload, RunWorkload::MissingWorkload) => true,
+            (RunWorkload::System((name, error)), RunWorkload::System((other_name, other))) => {
+                name == other_name && error.to_string() == other.to_string()
+            }
+            (RunWorkload::ApplyCommands(error), RunWorkload::ApplyCommands(other)) => {
+                error == other
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for RunWorkload {}
+
 impl Error for RunWorkload {}
 
 impl From<Borrow> for RunWorkload {
@@ -281,6 +386,12 @@ impl Debug for RunWorkload {
                 }
             },
             RunWorkload::MissingWorkload => fmt.write_str("No workload with this name exists."),
+            RunWorkload::System((name, error)) => {
+                fmt.write_fmt(format_args!("{} failed: {}", name, error))
+            }
+            RunWorkload::ApplyCommands(error) => {
+                fmt.write_fmt(format_args!("Commands failed: {}", error))
+            }
         }
     }
 }
@@ -291,6 +402,54 @@ impl Display for RunWorkload {
     }
 }
 
+/// Error occuring when a workload's systems can't be scheduled.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AddWorkload {
+    Borrow(Borrow),
+    Cycle(&'static str),
+    UnknownSystem(&'static str),
+    UnknownLabel(&'static str),
+}
+
+impl Error for AddWorkload {}
+
+impl From<Borrow> for AddWorkload {
+    fn from(borrow: Borrow) -> Self {
+        AddWorkload::Borrow(borrow)
+    }
+}
+
+impl Debug for AddWorkload {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            AddWorkload::Borrow(borrow) => match borrow {
+                Borrow::Unique => {
+                    fmt.write_str("Cannot mutably borrow pipeline while it's already borrowed.")
+                }
+                Borrow::Shared => unreachable!(),
+            },
+            AddWorkload::Cycle(name) => fmt.write_fmt(format_args!(
+                "{} is part of an ordering cycle, it has to run both before and after other systems.",
+                name
+            )),
+            AddWorkload::UnknownSystem(name) => fmt.write_fmt(format_args!(
+                "{} is used in an ordering constraint but isn't part of the workload.",
+                name
+            )),
+            AddWorkload::UnknownLabel(label) => fmt.write_fmt(format_args!(
+                "No system of the workload has the label \"{}\".",
+                label
+            )),
+        }
+    }
+}
+
+impl Display for AddWorkload {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
+
 /// Error occuring when trying to sort a single packed storage.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Sort {
@@ -314,3 +473,33 @@ impl Display for Sort {
         Debug::fmt(self, fmt)
     }
 }
+
+/// Error occuring when attaching an entity to a parent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Attach {
+    Cycle,
+    EntityIsNotAlive,
+    ParentIsNotAlive,
+}
+
+impl Error for Attach {}
+
+impl Debug for Attach {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Attach::Cycle => fmt.write_str(
+                "Cannot attach an entity to itself or one of its descendants, it would create a cycle.",
+            ),
+            Attach::EntityIsNotAlive => fmt.write_str("Entity has to be alive to be attached."),
+            Attach::ParentIsNotAlive => {
+                fmt.write_str("Parent has to be alive to attach an entity to it.")
+            }
+        }
+    }
+}
+
+impl Display for Attach {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        Debug::fmt(self, fmt)
+    }
+}
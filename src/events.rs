@@ -0,0 +1,172 @@
+use crate::commands::CommandQueue;
+use crate::error;
+use crate::sparse_set::SystemTicks;
+use crate::world::World;
+use crate::Unique;
+use std::sync::Mutex;
+
+/// Type used to borrow an event channel.
+///
+/// `Events<&T>` reads the events, `Events<&mut T>` sends them.\
+/// Readers borrow the channel immutably and can run in parallel,
+/// writers borrow it exclusively and are serialized with each other and with readers.
+///
+/// Sent events are recorded as commands and become visible at the next sync point,
+/// after the batch of systems they were sent in or when calling [World::apply_commands].\
+/// Each workload system reading events has its own cursor and sees each event exactly once,
+/// the first time it runs after the event became visible.
+///
+/// Events are dropped once all workload systems reading them read them,
+/// a reader that never runs again keeps all following events alive.\
+/// Reading events with [World::run] returns all events still stored and doesn't move any cursor.
+///
+/// The channel has to be registered with [World::register_events] first.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// struct Damage(u32);
+///
+/// let world = World::default();
+/// world.register_events::<Damage>();
+///
+/// world.add_workload(
+///     "Combat",
+///     WorkloadBuilder::default()
+///         .with_system(
+///             WorkloadSystem::from_fn::<Events<&mut Damage>, _>(|mut damages| {
+///                 damages.send(Damage(10));
+///             })
+///             .label("attack"),
+///         )
+///         // runs in the next batch, after the events became visible
+///         .with_system(
+///             WorkloadSystem::from_fn::<Events<&Damage>, _>(|damages| {
+///                 assert_eq!(damages.iter().map(|damage| damage.0).sum::<u32>(), 10);
+///             })
+///             .after_label("attack"),
+///         ),
+/// );
+///
+/// world.run_default();
+/// ```
+/// [World::run]: struct.World.html#method.run
+/// [World::apply_commands]: struct.World.html#method.apply_commands
+/// [World::register_events]: struct.World.html#method.register_events
+pub struct Events<T: ?Sized>(T);
+
+/// Events made visible and the tick at which they were, oldest first.
+///
+/// Stored as a unique storage inside `AllStorages`.
+pub(crate) struct EventQueue<T> {
+    events: Vec<(u32, T)>,
+    // id of each workload reader and tick up to which it read
+    cursors: Mutex<Vec<(usize, u32)>>,
+}
+
+impl<T> Default for EventQueue<T> {
+    fn default() -> Self {
+        EventQueue {
+            events: Vec::new(),
+            cursors: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl<T> EventQueue<T> {
+    /// Drops events all readers read and makes `events` visible at `tick`.
+    fn push(&mut self, events: Vec<T>, tick: u32) {
+        let cursors = self.cursors.get_mut().unwrap();
+        // events are stored in tick order, the ones read by all readers are at the front
+        let read = self
+            .events
+            .iter()
+            .take_while(|&&(event_tick, _)| {
+                cursors
+                    .iter()
+                    .all(|&(_, cursor)| tick.wrapping_sub(event_tick) >= tick.wrapping_sub(cursor))
+            })
+            .count();
+        self.events.drain(..read);
+        self.events
+            .extend(events.into_iter().map(|event| (tick, event)));
+    }
+}
+
+/// Reads the events sent since the system last ran.
+pub struct EventReader<'a, T> {
+    queue: &'a EventQueue<T>,
+    ticks: SystemTicks,
+}
+
+impl<'a, T> EventReader<'a, T> {
+    /// Moves the cursor of workload systems to the current tick.
+    pub(crate) fn new(queue: &'a EventQueue<T>, ticks: SystemTicks) -> Self {
+        if let Some(system_id) = ticks.system_id {
+            let mut cursors = queue.cursors.lock().unwrap();
+            match cursors.iter_mut().find(|(id, _)| *id == system_id) {
+                Some((_, cursor)) => *cursor = ticks.current,
+                None => cursors.push((system_id, ticks.current)),
+            }
+        }
+        EventReader { queue, ticks }
+    }
+    /// Returns an iterator over the events sent since the system last ran, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let ticks = self.ticks;
+        self.queue
+            .events
+            .iter()
+            .filter(move |(tick, _)| ticks.is_newer(*tick))
+            .map(|(_, event)| event)
+    }
+    /// Returns the number of events sent since the system last ran.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+    /// Returns true if no events were sent since the system last ran.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+/// Sends events.
+///
+/// Events are moved to the command buffer when the view is dropped.
+pub struct EventWriter<'a, T: 'static + Send + Sync> {
+    commands: &'a CommandQueue,
+    events: Vec<T>,
+}
+
+impl<'a, T: 'static + Send + Sync> EventWriter<'a, T> {
+    pub(crate) fn new(commands: &'a CommandQueue) -> Self {
+        EventWriter {
+            commands,
+            events: Vec::new(),
+        }
+    }
+    /// Sends `event` to all readers.
+    pub fn send(&mut self, event: T) {
+        self.events.push(event);
+    }
+}
+
+impl<T: 'static + Send + Sync> Extend<T> for EventWriter<'_, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, events: I) {
+        self.events.extend(events);
+    }
+}
+
+impl<T: 'static + Send + Sync> Drop for EventWriter<'_, T> {
+    fn drop(&mut self) {
+        if !self.events.is_empty() {
+            let events = std::mem::take(&mut self.events);
+            self.commands.push(Box::new(move |world: &World| {
+                let tick = world.next_tick();
+                world.try_run::<Unique<&mut EventQueue<T>>, _, _>(|queue| {
+                    queue.push(events, tick)
+                })?;
+                Ok::<(), error::ApplyCommands>(())
+            }));
+        }
+    }
+}
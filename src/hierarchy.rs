@@ -0,0 +1,382 @@
+use crate::error;
+use crate::sparse_set::{View, ViewMut};
+use crate::storage::{EntitiesView, EntitiesViewMut, EntityId};
+
+// Children of an entity form a circular doubly linked list.
+// The parent only knows the first child and how many there are,
+// each child knows its parent and its previous and next siblings.
+// Links are plain EntityIds, their version is checked when following them
+// so a link to a deleted entity is never followed.
+
+/// Component of entities with at least one child.
+pub struct Parent {
+    pub(crate) num_children: usize,
+    pub(crate) first_child: EntityId,
+}
+
+impl Parent {
+    /// Returns the number of children of this entity.
+    pub fn num_children(&self) -> usize {
+        self.num_children
+    }
+    /// Returns the first child of this entity.
+    pub fn first_child(&self) -> EntityId {
+        self.first_child
+    }
+}
+
+/// Component of entities attached to a parent.
+pub struct Child {
+    pub(crate) parent: EntityId,
+    pub(crate) prev: EntityId,
+    pub(crate) next: EntityId,
+}
+
+impl Child {
+    /// Returns the parent of this entity.
+    pub fn parent(&self) -> EntityId {
+        self.parent
+    }
+}
+
+/// Modifies the hierarchy formed by `Parent` and `Child` components.
+///
+/// Implemented for `(&EntitiesView, &mut ViewMut<Parent>, &mut ViewMut<Child>)`
+/// and `(&EntitiesViewMut, &mut ViewMut<Parent>, &mut ViewMut<Child>)`.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// let world = World::new::<(Parent, Child)>();
+///
+/// world.run::<(EntitiesMut, &mut Parent, &mut Child), _, _>(|(mut entities, mut parents, mut children)| {
+///     let root = entities.add_entity((), ());
+///     let child = entities.add_entity((), ());
+///
+///     (&entities, &mut parents, &mut children).attach(child, root);
+///     assert_eq!((&parents, &children).parent(child), Some(root));
+///
+///     (&entities, &mut parents, &mut children).detach(child);
+///     assert_eq!((&parents, &children).parent(child), None);
+/// });
+/// ```
+pub trait Hierarchy {
+    /// Attaches `entity` as the last child of `parent`.\
+    /// If `entity` already has a parent, it is reparented.
+    ///
+    /// Fails if `entity` or `parent` isn't alive or if `parent` is `entity` or one of its descendants.
+    fn try_attach(self, entity: EntityId, parent: EntityId) -> Result<(), error::Attach>;
+    /// Attaches `entity` as the last child of `parent`.\
+    /// If `entity` already has a parent, it is reparented.
+    ///
+    /// Unwraps errors.
+    fn attach(self, entity: EntityId, parent: EntityId);
+    /// Detaches `entity` from its parent, `entity` keeps its own children.\
+    /// Does nothing if `entity` doesn't have a parent.
+    fn detach(self, entity: EntityId);
+}
+
+impl Hierarchy
+    for (
+        &EntitiesView<'_>,
+        &mut ViewMut<'_, Parent>,
+        &mut ViewMut<'_, Child>,
+    )
+{
+    fn try_attach(self, entity: EntityId, parent: EntityId) -> Result<(), error::Attach> {
+        let (entities, parents, children) = self;
+
+        if !entities.is_alive(entity) {
+            Err(error::Attach::EntityIsNotAlive)
+        } else if !entities.is_alive(parent) {
+            Err(error::Attach::ParentIsNotAlive)
+        } else {
+            attach(parents, children, entity, parent)
+        }
+    }
+    fn attach(self, entity: EntityId, parent: EntityId) {
+        self.try_attach(entity, parent).unwrap()
+    }
+    fn detach(self, entity: EntityId) {
+        let (_, parents, children) = self;
+        detach(parents, children, entity);
+    }
+}
+
+impl Hierarchy
+    for (
+        &EntitiesViewMut<'_>,
+        &mut ViewMut<'_, Parent>,
+        &mut ViewMut<'_, Child>,
+    )
+{
+    fn try_attach(self, entity: EntityId, parent: EntityId) -> Result<(), error::Attach> {
+        let (entities, parents, children) = self;
+        (&entities.as_non_mut(), parents, children).try_attach(entity, parent)
+    }
+    fn attach(self, entity: EntityId, parent: EntityId) {
+        self.try_attach(entity, parent).unwrap()
+    }
+    fn detach(self, entity: EntityId) {
+        let (_, parents, children) = self;
+        detach(parents, children, entity);
+    }
+}
+
+/// Adds `entity` at the end of `parent`'s children, both are known to be alive.
+fn attach(
+    parents: &mut ViewMut<'_, Parent>,
+    children: &mut ViewMut<'_, Child>,
+    entity: EntityId,
+    parent: EntityId,
+) -> Result<(), error::Attach> {
+    if entity == parent
+        || (&parents.as_non_mut(), &children.as_non_mut())
+            .ancestors(parent)
+            .any(|ancestor| ancestor == entity)
+    {
+        return Err(error::Attach::Cycle);
+    }
+
+    detach(parents, children, entity);
+
+    let first_child = parents.get_mut(parent).map(|parent| {
+        parent.num_children += 1;
+        parent.first_child
+    });
+    // the first child's Child component can only be missing if the list was corrupted
+    let last_child = first_child.and_then(|first_child| Some(children.get(first_child)?.prev));
+
+    if let (Some(first_child), Some(last_child)) = (first_child, last_child) {
+        children.insert(
+            Child {
+                parent,
+                prev: last_child,
+                next: first_child,
+            },
+            entity,
+        );
+        children.get_mut(last_child).unwrap().next = entity;
+        children.get_mut(first_child).unwrap().prev = entity;
+    } else {
+        parents.insert(
+            Parent {
+                num_children: 1,
+                first_child: entity,
+            },
+            parent,
+        );
+        children.insert(
+            Child {
+                parent,
+                prev: entity,
+                next: entity,
+            },
+            entity,
+        );
+    }
+
+    Ok(())
+}
+
+/// Removes `entity` from its parent's children.
+pub(crate) fn detach(
+    parents: &mut ViewMut<'_, Parent>,
+    children: &mut ViewMut<'_, Child>,
+    entity: EntityId,
+) {
+    if let Some(child) = children.remove(entity) {
+        // the parent might have been deleted, in this case there is no list to fix
+        let remaining = parents.get_mut(child.parent).map(|parent| {
+            parent.num_children -= 1;
+            if parent.first_child == entity {
+                parent.first_child = child.next;
+            }
+            parent.num_children
+        });
+
+        match remaining {
+            Some(0) => {
+                parents.remove(child.parent);
+            }
+            Some(_) => {
+                if let Some(prev) = children.get_mut(child.prev) {
+                    prev.next = child.next;
+                }
+                if let Some(next) = children.get_mut(child.next) {
+                    next.prev = child.prev;
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Walks the hierarchy formed by `Parent` and `Child` components.
+///
+/// Implemented for `(&View<Parent>, &View<Child>)` and `(&ViewMut<Parent>, &ViewMut<Child>)`.\
+/// Links to deleted entities are detected using `EntityId`'s version and never followed.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// let world = World::new::<(Parent, Child)>();
+///
+/// world.run::<(EntitiesMut, &mut Parent, &mut Child), _, _>(|(mut entities, mut parents, mut children)| {
+///     let root = entities.add_entity((), ());
+///     let child1 = entities.add_entity((), ());
+///     let child2 = entities.add_entity((), ());
+///     let grand_child = entities.add_entity((), ());
+///
+///     (&entities, &mut parents, &mut children).attach(child1, root);
+///     (&entities, &mut parents, &mut children).attach(child2, root);
+///     (&entities, &mut parents, &mut children).attach(grand_child, child1);
+///
+///     let hierarchy = (&parents, &children);
+///     assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), vec![child1, child2]);
+///     assert_eq!(hierarchy.ancestors(grand_child).collect::<Vec<_>>(), vec![child1, root]);
+///     assert_eq!(
+///         hierarchy.descendants(root).collect::<Vec<_>>(),
+///         vec![child1, grand_child, child2]
+///     );
+/// });
+/// ```
+pub trait HierarchyIter<'a> {
+    /// Returns the parent of `entity`.
+    fn parent(self, entity: EntityId) -> Option<EntityId>;
+    /// Returns an iterator over `entity`'s direct children.
+    fn children(self, entity: EntityId) -> ChildrenIter<'a>;
+    /// Returns an iterator over `entity`'s ancestors, starting with its parent.
+    fn ancestors(self, entity: EntityId) -> AncestorsIter<'a>;
+    /// Returns an iterator over all `entity`'s descendants, depth first.
+    fn descendants(self, entity: EntityId) -> DescendantsIter<'a>;
+}
+
+impl<'a> HierarchyIter<'a> for (&View<'a, Parent>, &View<'a, Child>) {
+    fn parent(self, entity: EntityId) -> Option<EntityId> {
+        let (parents, children) = self;
+
+        let parent = children.get(entity)?.parent;
+        if parents.contains(parent) {
+            Some(parent)
+        } else {
+            None
+        }
+    }
+    fn children(self, entity: EntityId) -> ChildrenIter<'a> {
+        let (parents, children) = self;
+
+        match parents.get(entity) {
+            Some(parent) => ChildrenIter {
+                children: children.clone(),
+                current: parent.first_child,
+                remaining: parent.num_children,
+            },
+            None => ChildrenIter {
+                children: children.clone(),
+                current: entity,
+                remaining: 0,
+            },
+        }
+    }
+    fn ancestors(self, entity: EntityId) -> AncestorsIter<'a> {
+        let (parents, children) = self;
+
+        AncestorsIter {
+            parents: parents.clone(),
+            children: children.clone(),
+            current: entity,
+        }
+    }
+    fn descendants(self, entity: EntityId) -> DescendantsIter<'a> {
+        let (parents, children) = self;
+
+        DescendantsIter {
+            parents: parents.clone(),
+            children: children.clone(),
+            stack: vec![(parents, children).children(entity)],
+        }
+    }
+}
+
+impl<'a> HierarchyIter<'a> for (&'a ViewMut<'_, Parent>, &'a ViewMut<'_, Child>) {
+    fn parent(self, entity: EntityId) -> Option<EntityId> {
+        (&self.0.as_non_mut(), &self.1.as_non_mut()).parent(entity)
+    }
+    fn children(self, entity: EntityId) -> ChildrenIter<'a> {
+        (&self.0.as_non_mut(), &self.1.as_non_mut()).children(entity)
+    }
+    fn ancestors(self, entity: EntityId) -> AncestorsIter<'a> {
+        (&self.0.as_non_mut(), &self.1.as_non_mut()).ancestors(entity)
+    }
+    fn descendants(self, entity: EntityId) -> DescendantsIter<'a> {
+        (&self.0.as_non_mut(), &self.1.as_non_mut()).descendants(entity)
+    }
+}
+
+/// Iterator over the children of an entity.
+pub struct ChildrenIter<'a> {
+    children: View<'a, Child>,
+    current: EntityId,
+    remaining: usize,
+}
+
+impl Iterator for ChildrenIter<'_> {
+    type Item = EntityId;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            let entity = self.current;
+            match self.children.get(entity) {
+                Some(child) => {
+                    self.remaining -= 1;
+                    self.current = child.next;
+                    Some(entity)
+                }
+                // dangling link
+                None => {
+                    self.remaining = 0;
+                    None
+                }
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over the ancestors of an entity.
+pub struct AncestorsIter<'a> {
+    parents: View<'a, Parent>,
+    children: View<'a, Child>,
+    current: EntityId,
+}
+
+impl Iterator for AncestorsIter<'_> {
+    type Item = EntityId;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current = (&self.parents, &self.children).parent(self.current)?;
+        Some(self.current)
+    }
+}
+
+/// Iterator over the descendants of an entity, depth first.
+pub struct DescendantsIter<'a> {
+    parents: View<'a, Parent>,
+    children: View<'a, Child>,
+    stack: Vec<ChildrenIter<'a>>,
+}
+
+impl Iterator for DescendantsIter<'_> {
+    type Item = EntityId;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(children) = self.stack.last_mut() {
+            if let Some(entity) = children.next() {
+                let grand_children = (&self.parents, &self.children).children(entity);
+                self.stack.push(grand_children);
+                return Some(entity);
+            } else {
+                self.stack.pop();
+            }
+        }
+
+        None
+    }
+}
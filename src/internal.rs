@@ -1,2 +1,3 @@
 pub use crate::iter::iterators;
+pub use crate::sparse_set::SystemTicks;
 pub use crate::storage::EntitiesViewMut;
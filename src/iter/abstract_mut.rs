@@ -1,4 +1,7 @@
+use crate::added::Added;
+use crate::changed::Changed;
 use crate::not::Not;
+use crate::optional::Optional;
 use crate::sparse_set::{Pack, RawViewMut, View};
 use crate::storage::EntityId;
 
@@ -8,6 +11,8 @@ use crate::storage::EntityId;
 pub trait AbstractMut: Clone + Send {
     type Out;
     type Slice;
+    // false when get_data_slice can't be called, chunk iterators check it
+    const SLICEABLE: bool = true;
     // # Safety
     // The lifetime has to be valid
     unsafe fn get_data(&mut self, index: usize) -> Self::Out;
@@ -98,9 +103,13 @@ impl<'a, T: 'a + Send + Sync> AbstractMut for RawViewMut<'a, T> {
     type Out = &'a mut T;
     type Slice = &'a mut [T];
     unsafe fn get_data(&mut self, index: usize) -> Self::Out {
+        self.mark_tick(index);
         &mut *self.data.add(index)
     }
     unsafe fn get_data_slice(&mut self, indices: std::ops::Range<usize>) -> Self::Slice {
+        for index in indices.clone() {
+            self.mark_tick(index);
+        }
         std::slice::from_raw_parts_mut(self.data.add(indices.start), indices.end - indices.start)
     }
     fn indices(&self) -> *const EntityId {
@@ -117,7 +126,7 @@ impl<'a, T: 'a + Send + Sync> AbstractMut for RawViewMut<'a, T> {
                     *self.sparse.add((*self.dense.add(non_mod)).index()) = non_mod;
                     *self.sparse.add((*self.dense.add(index)).index()) = index;
                     pack.modified += 1;
-                    &mut *self.data.add(non_mod)
+                    self.get_data(non_mod)
                 } else {
                     self.get_data(index)
                 }
@@ -137,7 +146,7 @@ impl<'a, T: 'a + Send + Sync> AbstractMut for RawViewMut<'a, T> {
                     *self.sparse.add((*self.dense.add(non_mod)).index()) = non_mod;
                     *self.sparse.add((*self.dense.add(index)).index()) = index;
                     pack.modified += 1;
-                    &mut *self.data.add(non_mod)
+                    self.get_data(non_mod)
                 } else {
                     self.get_data(index)
                 }
@@ -165,6 +174,7 @@ impl<'a, T: 'a + Send + Sync> AbstractMut for RawViewMut<'a, T> {
 impl<'a, T: Send + Sync> AbstractMut for Not<View<'a, T>> {
     type Out = ();
     type Slice = ();
+    const SLICEABLE: bool = false;
     unsafe fn get_data(&mut self, index: usize) -> Self::Out {
         if index != std::usize::MAX {
             unreachable!()
@@ -198,6 +208,7 @@ impl<'a, T: Send + Sync> AbstractMut for Not<View<'a, T>> {
 impl<'a, T: Send + Sync> AbstractMut for &Not<View<'a, T>> {
     type Out = ();
     type Slice = ();
+    const SLICEABLE: bool = false;
     unsafe fn get_data(&mut self, index: usize) -> Self::Out {
         if index != std::usize::MAX {
             unreachable!()
@@ -231,6 +242,7 @@ impl<'a, T: Send + Sync> AbstractMut for &Not<View<'a, T>> {
 impl<'a, T: Send + Sync> AbstractMut for Not<&View<'a, T>> {
     type Out = ();
     type Slice = ();
+    const SLICEABLE: bool = false;
     unsafe fn get_data(&mut self, index: usize) -> Self::Out {
         if index != std::usize::MAX {
             unreachable!()
@@ -264,6 +276,7 @@ impl<'a, T: Send + Sync> AbstractMut for Not<&View<'a, T>> {
 impl<'a, T: Send + Sync> AbstractMut for Not<RawViewMut<'a, T>> {
     type Out = ();
     type Slice = ();
+    const SLICEABLE: bool = false;
     unsafe fn get_data(&mut self, index: usize) -> Self::Out {
         if index != std::usize::MAX {
             unreachable!()
@@ -293,3 +306,120 @@ impl<'a, T: Send + Sync> AbstractMut for Not<RawViewMut<'a, T>> {
         std::usize::MAX
     }
 }
+
+// `std::usize::MAX` is used as index of entities without component
+impl<T: AbstractMut> AbstractMut for Optional<T> {
+    type Out = Option<T::Out>;
+    type Slice = Option<T::Slice>;
+    const SLICEABLE: bool = T::SLICEABLE;
+    unsafe fn get_data(&mut self, index: usize) -> Self::Out {
+        if index != std::usize::MAX {
+            Some(self.0.get_data(index))
+        } else {
+            None
+        }
+    }
+    unsafe fn get_data_slice(&mut self, indices: std::ops::Range<usize>) -> Self::Slice {
+        Some(self.0.get_data_slice(indices))
+    }
+    fn indices(&self) -> *const EntityId {
+        self.0.indices()
+    }
+    unsafe fn mark_modified(&mut self, index: usize) -> Self::Out {
+        if index != std::usize::MAX {
+            Some(self.0.mark_modified(index))
+        } else {
+            None
+        }
+    }
+    unsafe fn mark_id_modified(&mut self, entity: EntityId) -> Self::Out {
+        if self.0.index_of(entity).is_some() {
+            Some(self.0.mark_id_modified(entity))
+        } else {
+            None
+        }
+    }
+    unsafe fn id_at(&self, index: usize) -> EntityId {
+        self.0.id_at(index)
+    }
+    fn index_of(&self, entity: EntityId) -> Option<usize> {
+        Some(self.0.index_of(entity).unwrap_or(std::usize::MAX))
+    }
+    unsafe fn index_of_unchecked(&self, entity: EntityId) -> usize {
+        self.0.index_of(entity).unwrap_or(std::usize::MAX)
+    }
+}
+
+// `Added` and `Changed` filter entities based on their component's ticks
+macro_rules! tick_filter {
+    ($filter: ident, $is_newer: ident) => {
+        impl<'a, T: Send + Sync> AbstractMut for $filter<View<'a, T>> {
+            type Out = &'a T;
+            type Slice = &'a [T];
+            unsafe fn get_data(&mut self, index: usize) -> Self::Out {
+                self.0.get_data(index)
+            }
+            unsafe fn get_data_slice(&mut self, indices: std::ops::Range<usize>) -> Self::Slice {
+                self.0.get_data_slice(indices)
+            }
+            fn indices(&self) -> *const EntityId {
+                self.0.indices()
+            }
+            unsafe fn mark_modified(&mut self, index: usize) -> Self::Out {
+                self.0.mark_modified(index)
+            }
+            unsafe fn mark_id_modified(&mut self, entity: EntityId) -> Self::Out {
+                self.0.mark_id_modified(entity)
+            }
+            unsafe fn id_at(&self, index: usize) -> EntityId {
+                self.0.id_at(index)
+            }
+            fn index_of(&self, entity: EntityId) -> Option<usize> {
+                if self.0.$is_newer(entity) {
+                    self.0.index_of(entity)
+                } else {
+                    None
+                }
+            }
+            unsafe fn index_of_unchecked(&self, entity: EntityId) -> usize {
+                self.0.index_of_unchecked(entity)
+            }
+        }
+
+        impl<'a, T: Send + Sync> AbstractMut for $filter<&View<'a, T>> {
+            type Out = &'a T;
+            type Slice = &'a [T];
+            unsafe fn get_data(&mut self, index: usize) -> Self::Out {
+                (&mut self.0).get_data(index)
+            }
+            unsafe fn get_data_slice(&mut self, indices: std::ops::Range<usize>) -> Self::Slice {
+                (&mut self.0).get_data_slice(indices)
+            }
+            fn indices(&self) -> *const EntityId {
+                (&self.0).indices()
+            }
+            unsafe fn mark_modified(&mut self, index: usize) -> Self::Out {
+                (&mut self.0).mark_modified(index)
+            }
+            unsafe fn mark_id_modified(&mut self, entity: EntityId) -> Self::Out {
+                (&mut self.0).mark_id_modified(entity)
+            }
+            unsafe fn id_at(&self, index: usize) -> EntityId {
+                (&self.0).id_at(index)
+            }
+            fn index_of(&self, entity: EntityId) -> Option<usize> {
+                if self.0.$is_newer(entity) {
+                    (&self.0).index_of(entity)
+                } else {
+                    None
+                }
+            }
+            unsafe fn index_of_unchecked(&self, entity: EntityId) -> usize {
+                (&self.0).index_of_unchecked(entity)
+            }
+        }
+    };
+}
+
+tick_filter![Added, is_added];
+tick_filter![Changed, is_changed];
@@ -0,0 +1,221 @@
+use super::abstract_mut::AbstractMut;
+use super::into_abstract::IntoAbstract;
+use crate::any_of::AnyOf;
+use crate::sparse_set::{Pack, PackInfo, UpdatePack, View, ViewMut};
+use crate::storage::EntityId;
+use std::any::TypeId;
+use std::sync::Arc;
+
+// `AnyOf` is never part of a pack, it only has to tell if one of its storages is update packed.
+static NO_PACK: PackInfo<()> = PackInfo {
+    pack: Pack::NoPack,
+    observer_types: Vec::new(),
+};
+static UPDATE_PACK: PackInfo<()> = PackInfo {
+    pack: Pack::Update(UpdatePack {
+        inserted: 0,
+        modified: 0,
+        removed: Vec::new(),
+    }),
+    observer_types: Vec::new(),
+};
+
+// Storages `AnyOf` is made of.
+// Filters and `AnyOf` itself don't have a dense array of their own, they can't be part of an `AnyOf`.
+#[doc(hidden)]
+pub trait AnyOfStorage: IntoAbstract {
+    // entities with a component
+    fn dense(&self) -> &[EntityId];
+    fn contains(&self, entity: EntityId) -> bool;
+}
+
+impl<'a, T: 'static + Send + Sync> AnyOfStorage for View<'a, T> {
+    fn dense(&self) -> &[EntityId] {
+        self.dense
+    }
+    fn contains(&self, entity: EntityId) -> bool {
+        View::contains(self, entity)
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> AnyOfStorage for &View<'a, T> {
+    fn dense(&self) -> &[EntityId] {
+        self.dense
+    }
+    fn contains(&self, entity: EntityId) -> bool {
+        View::contains(self, entity)
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> AnyOfStorage for ViewMut<'a, T> {
+    fn dense(&self) -> &[EntityId] {
+        self.dense
+    }
+    fn contains(&self, entity: EntityId) -> bool {
+        ViewMut::contains(self, entity)
+    }
+}
+
+impl<'a: 'b, 'b, T: 'static + Send + Sync> AnyOfStorage for &'b ViewMut<'a, T> {
+    fn dense(&self) -> &[EntityId] {
+        self.dense
+    }
+    fn contains(&self, entity: EntityId) -> bool {
+        ViewMut::contains(self, entity)
+    }
+}
+
+impl<'a: 'b, 'b, T: 'static + Send + Sync> AnyOfStorage for &'b mut ViewMut<'a, T> {
+    fn dense(&self) -> &[EntityId] {
+        self.dense
+    }
+    fn contains(&self, entity: EntityId) -> bool {
+        ViewMut::contains(self, entity)
+    }
+}
+
+/// Number of entities present in at least one of the `dense` arrays.\
+/// Each entity is counted in the first storage containing it, without allocating.
+fn union_len(denses: &[&[EntityId]], contains: &[&dyn Fn(EntityId) -> bool]) -> usize {
+    denses
+        .iter()
+        .enumerate()
+        .map(|(i, dense)| {
+            dense
+                .iter()
+                .filter(|&&entity| !contains[..i].iter().any(|contains| contains(entity)))
+                .count()
+        })
+        .sum()
+}
+
+/// Entities present in at least one of the `dense` arrays, in order of first appearance.\
+/// `sparse` maps an entity's index to its position in the returned ids.
+fn union(denses: &[&[EntityId]]) -> (Vec<EntityId>, Vec<usize>) {
+    let mut ids = Vec::new();
+    let mut sparse = Vec::new();
+    for &entity in denses.iter().flat_map(|dense| dense.iter()) {
+        if entity.index() >= sparse.len() {
+            sparse.resize(entity.index() + 1, usize::MAX);
+        }
+        let index = sparse[entity.index()];
+        if index == usize::MAX || ids[index] != entity {
+            sparse[entity.index()] = ids.len();
+            ids.push(entity);
+        }
+    }
+    (ids, sparse)
+}
+
+// Shared so the pointer given by `indices` stays valid in clones
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct AnyOfView<T> {
+    views: T,
+    ids: Arc<[EntityId]>,
+    sparse: Arc<[usize]>,
+}
+
+macro_rules! impl_any_of {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: AnyOfStorage),+> IntoAbstract for AnyOf<($($type,)+)>
+        where
+            $($type::PackType: 'static),+
+        {
+            type AbsView = AnyOfView<($($type::AbsView,)+)>;
+            type PackType = ();
+            fn into_abstract(self) -> Self::AbsView {
+                let (ids, sparse) = union(&[$(self.0.$index.dense()),+]);
+                AnyOfView {
+                    views: ($(self.0.$index.into_abstract(),)+),
+                    ids: ids.into(),
+                    sparse: sparse.into(),
+                }
+            }
+            // the union is only built once, by `into_abstract`
+            fn len(&self) -> Option<usize> {
+                Some(union_len(
+                    &[$(self.0.$index.dense()),+],
+                    &[$(&|entity| self.0.$index.contains(entity)),+],
+                ))
+            }
+            fn pack_info(&self) -> &PackInfo<Self::PackType> {
+                if false $(|| matches!(self.0.$index.pack_info().pack, Pack::Update(_)))+ {
+                    &UPDATE_PACK
+                } else {
+                    &NO_PACK
+                }
+            }
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<AnyOf<($($type::PackType,)+)>>()
+            }
+            fn modified(&self) -> usize {
+                usize::MAX
+            }
+        }
+
+        impl<$($type: AbstractMut),+> AbstractMut for AnyOfView<($($type,)+)> {
+            type Out = ($(Option<$type::Out>,)+);
+            type Slice = ();
+            const SLICEABLE: bool = false;
+            unsafe fn get_data(&mut self, index: usize) -> Self::Out {
+                let entity = *self.ids.get_unchecked(index);
+                ($(
+                    match self.views.$index.index_of(entity) {
+                        Some(index) => Some(self.views.$index.get_data(index)),
+                        None => None,
+                    },
+                )+)
+            }
+            unsafe fn get_data_slice(&mut self, _: std::ops::Range<usize>) -> Self::Slice {
+                unreachable!()
+            }
+            fn indices(&self) -> *const EntityId {
+                self.ids.as_ptr()
+            }
+            unsafe fn mark_modified(&mut self, index: usize) -> Self::Out {
+                let entity = *self.ids.get_unchecked(index);
+                ($(
+                    match self.views.$index.index_of(entity) {
+                        Some(index) => Some(self.views.$index.mark_modified(index)),
+                        None => None,
+                    },
+                )+)
+            }
+            unsafe fn mark_id_modified(&mut self, entity: EntityId) -> Self::Out {
+                ($(
+                    match self.views.$index.index_of(entity) {
+                        Some(_) => Some(self.views.$index.mark_id_modified(entity)),
+                        None => None,
+                    },
+                )+)
+            }
+            unsafe fn id_at(&self, index: usize) -> EntityId {
+                *self.ids.get_unchecked(index)
+            }
+            fn index_of(&self, entity: EntityId) -> Option<usize> {
+                match self.sparse.get(entity.index()) {
+                    Some(&index) if index != usize::MAX && self.ids[index] == entity => {
+                        Some(index)
+                    }
+                    _ => None,
+                }
+            }
+            unsafe fn index_of_unchecked(&self, entity: EntityId) -> usize {
+                *self.sparse.get_unchecked(entity.index())
+            }
+        }
+    }
+}
+
+macro_rules! any_of {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_any_of![$(($type, $index))*];
+        any_of![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_any_of![$(($type, $index))*];
+    }
+}
+
+any_of![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -1,4 +1,4 @@
-use super::{CurrentId, Shiperator};
+use super::{CurrentId, DoubleEndedShiperator, ExactSizeShiperator, Shiperator};
 
 pub struct Enumerate<I> {
     iter: I,
@@ -23,8 +23,21 @@ impl<I: Shiperator> Shiperator for Enumerate<I> {
     unsafe fn post_process(&mut self, (current, item): Self::Item) -> Self::Item {
         (current, self.iter.post_process(item))
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<I: DoubleEndedShiperator + ExactSizeShiperator> DoubleEndedShiperator for Enumerate<I> {
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.first_pass_back()?;
+        // the item was removed from the back so the remaining length is its offset
+        Some((self.count + self.iter.len(), item))
+    }
+}
+
+impl<I: ExactSizeShiperator> ExactSizeShiperator for Enumerate<I> {}
+
 impl<I: CurrentId> CurrentId for Enumerate<I> {
     type Id = I::Id;
 
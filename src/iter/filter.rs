@@ -1,4 +1,8 @@
-use super::{CurrentId, Shiperator};
+#[cfg(feature = "parallel")]
+use super::iterators::{SplitShiperator, UpdateShiperator};
+use super::{CurrentId, DoubleEndedShiperator, Shiperator};
+#[cfg(feature = "parallel")]
+use crate::EntityId;
 
 pub struct Filter<I, P> {
     iter: I,
@@ -28,6 +32,23 @@ where
     unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
         self.iter.post_process(item)
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedShiperator, P> DoubleEndedShiperator for Filter<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+        while let Some(item) = self.iter.first_pass_back() {
+            if (self.pred)(&item) {
+                return Some(item);
+            }
+        }
+        None
+    }
 }
 
 impl<I: CurrentId, P> CurrentId for Filter<I, P>
@@ -40,3 +61,32 @@ where
         self.iter.current_id()
     }
 }
+
+// SAFE each half gets its own predicate
+#[cfg(feature = "parallel")]
+unsafe impl<I: SplitShiperator, P: Clone + Send> SplitShiperator for Filter<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.iter.split_at(index);
+        (
+            Filter::new(left, self.pred.clone()),
+            Filter::new(right, self.pred),
+        )
+    }
+}
+
+// the predicate is only run by first_pass, items fetched later already passed it
+#[cfg(feature = "parallel")]
+unsafe impl<I: UpdateShiperator, P: Clone + Send> UpdateShiperator for Filter<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    unsafe fn mark_id(&mut self, entity: EntityId) {
+        self.iter.mark_id(entity)
+    }
+    unsafe fn item_of(&mut self, entity: EntityId) -> Self::Item {
+        self.iter.item_of(entity)
+    }
+}
@@ -1,5 +1,8 @@
 use super::abstract_mut::AbstractMut;
+use crate::added::Added;
+use crate::changed::Changed;
 use crate::not::Not;
+use crate::optional::Optional;
 use crate::sparse_set::{Pack, PackInfo, RawViewMut, View, ViewMut};
 use std::any::TypeId;
 
@@ -283,6 +286,31 @@ impl<'a: 'b, 'b, T: 'static + Send + Sync> IntoAbstract for Not<&'b mut ViewMut<
     }
 }
 
+// `Optional` never drives an iteration and can't be part of a tight or loose iteration,
+// the type id is different from `T`'s to not match any pack.
+impl<T: IntoAbstract> IntoAbstract for Optional<T>
+where
+    T::PackType: 'static,
+{
+    type AbsView = Optional<T::AbsView>;
+    type PackType = T::PackType;
+    fn into_abstract(self) -> Self::AbsView {
+        Optional(self.0.into_abstract())
+    }
+    fn len(&self) -> Option<usize> {
+        None
+    }
+    fn pack_info(&self) -> &PackInfo<Self::PackType> {
+        self.0.pack_info()
+    }
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<Optional<T::PackType>>()
+    }
+    fn modified(&self) -> usize {
+        self.0.modified()
+    }
+}
+
 impl<'a, T: 'static + Send + Sync> IntoAbstract for RawViewMut<'a, T> {
     type AbsView = RawViewMut<'a, T>;
     type PackType = T;
@@ -305,3 +333,72 @@ impl<'a, T: 'static + Send + Sync> IntoAbstract for RawViewMut<'a, T> {
         }
     }
 }
+
+// `Added` and `Changed` never drive an iteration and can't be part of a tight or loose iteration,
+// the type id is different from `T`'s to not match any pack.
+macro_rules! tick_filter {
+    ($filter: ident) => {
+        impl<'a, T: 'static + Send + Sync> IntoAbstract for $filter<View<'a, T>> {
+            type AbsView = Self;
+            type PackType = T;
+            fn into_abstract(self) -> Self::AbsView {
+                self
+            }
+            fn len(&self) -> Option<usize> {
+                None
+            }
+            fn pack_info(&self) -> &PackInfo<Self::PackType> {
+                &self.0.pack_info
+            }
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<$filter<T>>()
+            }
+            fn modified(&self) -> usize {
+                std::usize::MAX
+            }
+        }
+
+        impl<'a: 'b, 'b, T: 'static + Send + Sync> IntoAbstract for $filter<&'b View<'a, T>> {
+            type AbsView = Self;
+            type PackType = T;
+            fn into_abstract(self) -> Self::AbsView {
+                self
+            }
+            fn len(&self) -> Option<usize> {
+                None
+            }
+            fn pack_info(&self) -> &PackInfo<Self::PackType> {
+                &self.0.pack_info
+            }
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<$filter<T>>()
+            }
+            fn modified(&self) -> usize {
+                std::usize::MAX
+            }
+        }
+
+        impl<'a: 'b, 'b, T: 'static + Send + Sync> IntoAbstract for $filter<&'b ViewMut<'a, T>> {
+            type AbsView = $filter<View<'b, T>>;
+            type PackType = T;
+            fn into_abstract(self) -> Self::AbsView {
+                $filter(self.0.as_non_mut())
+            }
+            fn len(&self) -> Option<usize> {
+                None
+            }
+            fn pack_info(&self) -> &PackInfo<Self::PackType> {
+                &self.0.pack_info
+            }
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<$filter<T>>()
+            }
+            fn modified(&self) -> usize {
+                std::usize::MAX
+            }
+        }
+    };
+}
+
+tick_filter![Added];
+tick_filter![Changed];
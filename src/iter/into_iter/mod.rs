@@ -55,3 +55,32 @@ pub trait IntoIter {
     #[cfg(feature = "parallel")]
     fn par_iter(self) -> Self::IntoParIter;
 }
+
+/// Trait used to iterate the owners of a loose pack as if they were tightly packed.
+///
+/// Only the packed prefix is covered: entities that have all the owned components
+/// but not the loose ones are left out. Use `iter` to visit all of them.
+pub trait IntoPackedPrefix: Sized {
+    type Tight;
+    /// Returns a tight iterator over the packed prefix of the loose pack owning all the storages,
+    /// giving access to `into_chunk` and `into_chunk_exact`.
+    /// Returns `self` if the storages aren't all owners of the same loose pack.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(u32, u64, i16)>();
+    /// world.loose_pack::<(u32, u64), (i16,)>();
+    ///
+    /// world.run::<(EntitiesMut, &mut u32, &mut u64, &mut i16), _, _>(|(mut entities, mut u32s, mut u64s, mut i16s)| {
+    ///     entities.add_entity((&mut u32s, &mut u64s, &mut i16s), (0, 10, 20));
+    ///     entities.add_entity((&mut u32s, &mut u64s), (1, 11));
+    ///
+    ///     let mut chunk = (&u32s, &u64s).packed_prefix().ok().unwrap().into_chunk(2);
+    ///     assert_eq!(chunk.next(), Some((&[0][..], &[10][..])));
+    ///     assert!(chunk.next().is_none());
+    ///
+    ///     assert_eq!((&u32s, &u64s).iter().count(), 2);
+    /// });
+    /// ```
+    fn packed_prefix(self) -> Result<Self::Tight, Self>;
+}
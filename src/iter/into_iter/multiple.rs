@@ -12,10 +12,6 @@ macro_rules! impl_iterators {
         $update: ident
         $(($type: ident, $index: tt))+
     ) => {
-        #[allow(dead_code)]
-        pub enum $par_iter<$($type),+>{
-            None(($($type,)+))
-        }
 
         impl<$($type: IntoAbstract),+> IntoIter for ($($type,)+) {
             type IntoIter = $iter<$($type,)+>;
@@ -102,6 +98,7 @@ macro_rules! impl_iterators {
                             data: ($(self.$index.into_abstract(),)+),
                             current: 0,
                             end: smallest,
+                            last: 0,
                         })
                     }
                     PackIter::Loose => {
@@ -124,6 +121,7 @@ macro_rules! impl_iterators {
                             end: smallest,
                             array,
                             indices: indices.unwrap(),
+                            last: 0,
                         })
                     }
                     PackIter::Update => {
@@ -147,6 +145,7 @@ macro_rules! impl_iterators {
                             end: smallest,
                             array: smallest_index,
                             current_id: EntityId::dead(),
+                            flagged_remaining: false,
                         })
                     }
                     PackIter::None => {
@@ -169,19 +168,49 @@ macro_rules! impl_iterators {
                             current: 0,
                             end: smallest,
                             array: smallest_index,
+                            last: 0,
                         })
                     }
                 }
             }
             #[cfg(feature = "parallel")]
             fn par_iter(self) -> Self::IntoParIter {
-                todo!()
-                /*match self.iter() {
-                    $iter::Tight(iter) => $par_iter::Tight($par_tight(iter)),
-                    $iter::Loose(iter) => $par_iter::Loose($par_loose(iter)),
-                    $iter::Update(iter) => $par_iter::Update($par_update(iter)),
-                    $iter::NonPacked(iter) => $par_iter::NonPacked($par_non_packed(iter)),
-                }*/
+                match self.iter() {
+                    $iter::Tight(iter) => ParIter::Tight(ParTight(iter)),
+                    $iter::Loose(iter) => ParIter::Loose(ParLoose(iter)),
+                    $iter::Update(iter) => ParIter::Update(ParUpdate(iter)),
+                    $iter::NonPacked(iter) => ParIter::NonPacked(ParNonPacked(iter)),
+                }
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> IntoPackedPrefix for ($($type,)+) {
+            type Tight = $tight<$($type,)+>;
+            fn packed_prefix(self) -> Result<Self::Tight, Self> {
+                let type_ids = [$(self.$index.type_id()),+];
+                let mut end = 0;
+
+                $({
+                    // filters don't have components to iterate
+                    let is_owner = match &self.$index.pack_info().pack {
+                        Pack::Loose(pack) => {
+                            end = pack.len;
+                            self.$index.len().is_some()
+                                && type_ids.iter().all(|type_id| pack.tight_types.contains(type_id))
+                        }
+                        _ => false,
+                    };
+                    if !is_owner {
+                        return Err(self);
+                    }
+                })+
+
+                Ok($tight {
+                    data: ($(self.$index.into_abstract(),)+),
+                    current: 0,
+                    end,
+                    last: 0,
+                })
             }
         }
     }
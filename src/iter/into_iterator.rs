@@ -0,0 +1,39 @@
+use super::{DoubleEndedShiperator, ExactSizeShiperator, Shiperator};
+
+/// `Iterator` yielding the items of a shiperator, see `Shiperator::into_iterator`.
+///
+/// Items are post processed when yielded, update packed components are flagged as modified at that point.
+pub struct ShipIter<I> {
+    iter: I,
+}
+
+impl<I> ShipIter<I> {
+    pub(super) fn new(iter: I) -> Self {
+        ShipIter { iter }
+    }
+}
+
+impl<I: Shiperator> Iterator for ShipIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Shiperator::next(&mut self.iter)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn fold<Acc, F>(self, acc: Acc, f: F) -> Acc
+    where
+        F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(acc, f)
+    }
+}
+
+impl<I: DoubleEndedShiperator> DoubleEndedIterator for ShipIter<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<I: ExactSizeShiperator> ExactSizeIterator for ShipIter<I> {}
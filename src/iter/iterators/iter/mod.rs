@@ -2,7 +2,8 @@ mod multiple;
 mod single;
 
 use super::{
-    loose::*, non_packed::*, tight::*, update::*, AbstractMut, CurrentId, IntoAbstract, Shiperator,
+    loose::*, non_packed::*, tight::*, update::*, AbstractMut, CurrentId, DoubleEndedShiperator,
+    ExactSizeShiperator, IntoAbstract, Shiperator,
 };
 
 pub use multiple::*;
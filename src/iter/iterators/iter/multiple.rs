@@ -27,13 +27,13 @@ macro_rules! impl_iterators {
         impl<$($type: IntoAbstract),+> $iter<$($type),+> {
             pub fn into_chunk(self, step: usize) -> Result<$chunk<$($type),+>, Self> {
                 match self {
-                    Self::Tight(tight) => Ok(tight.into_chunk(step)),
+                    Self::Tight(tight) if $($type::AbsView::SLICEABLE)&&+ => Ok(tight.into_chunk(step)),
                     _ => Err(self)
                 }
             }
             pub fn into_chunk_exact(self, step: usize) -> Result<$chunk_exact<$($type),+>, Self> {
                 match self {
-                    Self::Tight(tight) => Ok(tight.into_chunk_exact(step)),
+                    Self::Tight(tight) if $($type::AbsView::SLICEABLE)&&+ => Ok(tight.into_chunk_exact(step)),
                     _ => Err(self)
                 }
             }
@@ -58,6 +58,25 @@ macro_rules! impl_iterators {
                     Self::NonPacked(non_packed) => non_packed.post_process(item),
                 }
             }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self {
+                    Self::Tight(tight) => tight.size_hint(),
+                    Self::Loose(loose) => loose.size_hint(),
+                    Self::Update(update) => update.size_hint(),
+                    Self::NonPacked(non_packed) => non_packed.size_hint(),
+                }
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> DoubleEndedShiperator for $iter<$($type),+> {
+            unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+                match self {
+                    Self::Tight(tight) => tight.first_pass_back(),
+                    Self::Loose(loose) => loose.first_pass_back(),
+                    Self::Update(update) => update.first_pass_back(),
+                    Self::NonPacked(non_packed) => non_packed.first_pass_back(),
+                }
+            }
         }
         impl<$($type: IntoAbstract),+> CurrentId for $iter<$($type),+> {
             type Id = EntityId;
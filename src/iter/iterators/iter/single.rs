@@ -1,5 +1,6 @@
 use super::{
-    AbstractMut, Chunk1, ChunkExact1, CurrentId, IntoAbstract, Shiperator, Tight1, Update1,
+    AbstractMut, Chunk1, ChunkExact1, CurrentId, DoubleEndedShiperator, ExactSizeShiperator,
+    IntoAbstract, Shiperator, Tight1, Update1,
 };
 use crate::EntityId;
 
@@ -10,15 +11,21 @@ pub enum Iter1<T: IntoAbstract> {
 
 impl<T: IntoAbstract> Iter1<T> {
     pub fn into_chunk(self, step: usize) -> Result<Chunk1<T>, Self> {
+        if !T::AbsView::SLICEABLE {
+            return Err(self);
+        }
         match self {
             Self::Tight(tight) => Ok(tight.into_chunk(step)),
-            _ => Err(self),
+            Self::Update(update) => Ok(update.into_chunk(step)),
         }
     }
     pub fn into_chunk_exact(self, step: usize) -> Result<ChunkExact1<T>, Self> {
+        if !T::AbsView::SLICEABLE {
+            return Err(self);
+        }
         match self {
             Self::Tight(tight) => Ok(tight.into_chunk_exact(step)),
-            _ => Err(self),
+            Self::Update(update) => Ok(update.into_chunk_exact(step)),
         }
     }
 }
@@ -38,8 +45,25 @@ impl<T: IntoAbstract> Shiperator for Iter1<T> {
             Self::Update(update) => update.post_process(item),
         }
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Tight(tight) => tight.size_hint(),
+            Self::Update(update) => update.size_hint(),
+        }
+    }
 }
 
+impl<T: IntoAbstract> DoubleEndedShiperator for Iter1<T> {
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Tight(tight) => tight.first_pass_back(),
+            Self::Update(update) => update.first_pass_back(),
+        }
+    }
+}
+
+impl<T: IntoAbstract> ExactSizeShiperator for Iter1<T> {}
+
 impl<T: IntoAbstract> CurrentId for Iter1<T> {
     type Id = EntityId;
 
@@ -1,5 +1,9 @@
 mod multiple;
 
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::SplitShiperator;
+use super::{
+    AbstractMut, CurrentId, DoubleEndedShiperator, ExactSizeShiperator, IntoAbstract, Shiperator,
+};
 
 pub use multiple::*;
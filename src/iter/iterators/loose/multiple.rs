@@ -1,4 +1,8 @@
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::SplitShiperator;
+use super::{
+    AbstractMut, CurrentId, DoubleEndedShiperator, ExactSizeShiperator, IntoAbstract, Shiperator,
+};
 use crate::EntityId;
 
 macro_rules! impl_iterators {
@@ -16,6 +20,8 @@ macro_rules! impl_iterators {
             pub(crate) current: usize,
             pub(crate) end: usize,
             pub(crate) array: u32,
+            // index of the last item returned, from either end
+            pub(crate) last: usize,
         }
 
         impl<$($type: IntoAbstract),+> Shiperator for $loose<$($type),+> {
@@ -27,6 +33,7 @@ macro_rules! impl_iterators {
                     // and self.indices can't access out of bounds
                     let index = std::ptr::read(self.indices.add(self.current));
                     self.current += 1;
+                    self.last = self.current - 1;
                     let indices = ($(
                         if (self.array >> $index) & 1 != 0 {
                             self.current - 1
@@ -44,13 +51,67 @@ macro_rules! impl_iterators {
             unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
                 item
             }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.end - self.current;
+                (len, Some(len))
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> DoubleEndedShiperator for $loose<$($type),+> {
+            unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+                if self.current < self.end {
+                    self.end -= 1;
+                    self.last = self.end;
+                    // SAFE same as first_pass
+                    let index = std::ptr::read(self.indices.add(self.end));
+                    let indices = ($(
+                        if (self.array >> $index) & 1 != 0 {
+                            self.end
+                        } else {
+                            self.data.$index.index_of_unchecked(index)
+                        },
+                    )+);
+                    Some(($({
+                        self.data.$index.get_data(indices.$index)
+                    },)+))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> ExactSizeShiperator for $loose<$($type),+> {}
+
+        // SAFE each half reads and writes the components of its own range of entities
+        // and indices is only read
+        #[cfg(feature = "parallel")]
+        unsafe impl<$($type: IntoAbstract),+> SplitShiperator for $loose<$($type),+> {
+            fn split_at(self, index: usize) -> (Self, Self) {
+                let left = $loose {
+                    data: self.data.clone(),
+                    indices: self.indices,
+                    current: self.current,
+                    end: self.current + index,
+                    array: self.array,
+                    last: 0,
+                };
+                let right = $loose {
+                    data: self.data,
+                    indices: self.indices,
+                    current: self.current + index,
+                    end: self.end,
+                    array: self.array,
+                    last: 0,
+                };
+                (left, right)
+            }
         }
 
         impl<$($type: IntoAbstract),+> CurrentId for $loose<$($type),+> {
             type Id = EntityId;
 
             unsafe fn current_id(&self) -> Self::Id {
-                std::ptr::read(self.indices.add(self.current - 1))
+                std::ptr::read(self.indices.add(self.last))
             }
         }
 
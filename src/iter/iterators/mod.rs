@@ -1,15 +1,19 @@
 mod iter;
 mod loose;
 mod non_packed;
+#[cfg(feature = "parallel")]
+mod parallel;
 mod tight;
 mod update;
 
 use super::abstract_mut::AbstractMut;
 use super::into_abstract::IntoAbstract;
-use super::{CurrentId, Shiperator};
+use super::{CurrentId, DoubleEndedShiperator, ExactSizeShiperator, Shiperator};
 
 pub use iter::*;
 pub use loose::*;
 pub use non_packed::*;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
 pub use tight::*;
 pub use update::*;
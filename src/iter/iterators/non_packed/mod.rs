@@ -1,5 +1,7 @@
 mod multiple;
 
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::SplitShiperator;
+use super::{AbstractMut, CurrentId, DoubleEndedShiperator, IntoAbstract, Shiperator};
 
 pub use multiple::*;
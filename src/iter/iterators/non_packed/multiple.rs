@@ -1,4 +1,6 @@
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::SplitShiperator;
+use super::{AbstractMut, CurrentId, DoubleEndedShiperator, IntoAbstract, Shiperator};
 use crate::EntityId;
 
 macro_rules! impl_iterators {
@@ -16,6 +18,8 @@ macro_rules! impl_iterators {
             pub(crate) current: usize,
             pub(crate) end: usize,
             pub(crate) array: usize,
+            // index of the last item returned, from either end
+            pub(crate) last: usize,
         }
 
         impl<$($type: IntoAbstract),+> Shiperator for $non_packed<$($type),+> {
@@ -27,6 +31,7 @@ macro_rules! impl_iterators {
                     // and self.indices can't access out of bounds
                     let index = std::ptr::read(self.indices.add(self.current));
                     self.current += 1;
+                    self.last = self.current - 1;
                     let data_indices = ($(
                         if $index == self.array {
                             self.current - 1
@@ -45,13 +50,65 @@ macro_rules! impl_iterators {
             unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
                 item
             }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, Some(self.end - self.current))
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> DoubleEndedShiperator for $non_packed<$($type),+> {
+            unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+                while self.current < self.end {
+                    self.end -= 1;
+                    // SAFE same as first_pass
+                    let index = std::ptr::read(self.indices.add(self.end));
+                    let data_indices = ($(
+                        if $index == self.array {
+                            self.end
+                        } else {
+                            if let Some(index) = self.data.$index.index_of(index) {
+                                index
+                            } else {
+                                continue
+                            }
+                        },
+                    )+);
+                    self.last = self.end;
+                    return Some(($(self.data.$index.get_data(data_indices.$index),)+))
+                }
+                None
+            }
+        }
+
+        // SAFE each half reads and writes the components of its own range of entities
+        // and indices is only read
+        #[cfg(feature = "parallel")]
+        unsafe impl<$($type: IntoAbstract),+> SplitShiperator for $non_packed<$($type),+> {
+            fn split_at(self, index: usize) -> (Self, Self) {
+                let left = $non_packed {
+                    data: self.data.clone(),
+                    indices: self.indices,
+                    current: self.current,
+                    end: self.current + index,
+                    array: self.array,
+                    last: 0,
+                };
+                let right = $non_packed {
+                    data: self.data,
+                    indices: self.indices,
+                    current: self.current + index,
+                    end: self.end,
+                    array: self.array,
+                    last: 0,
+                };
+                (left, right)
+            }
         }
 
         impl<$($type: IntoAbstract),+> CurrentId for $non_packed<$($type),+> {
             type Id = EntityId;
 
             unsafe fn current_id(&self) -> Self::Id {
-                std::ptr::read(self.indices.add(self.current - 1))
+                std::ptr::read(self.indices.add(self.last))
             }
         }
 
@@ -0,0 +1,163 @@
+use super::{ParLoose, ParNonPacked, ParTight, ParUpdate, SplitShiperator, UpdateShiperator};
+use crate::iter::filter::Filter;
+use crate::iter::iterators::{loose::*, non_packed::*, tight::*, update::*};
+use crate::iter::with_id::WithId;
+use crate::iter::CurrentId;
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::ParallelIterator;
+
+/// Parallel iterator over a single storage.
+///
+/// This enum allows to abstract away what kind of iterator you really get.
+pub enum ParIter1<T, U> {
+    Tight(ParTight<T>),
+    Update(ParUpdate<U>),
+}
+
+impl<T, U> ParIter1<T, U>
+where
+    T: SplitShiperator + CurrentId,
+    U: UpdateShiperator<Item = T::Item>,
+{
+    /// Makes the iterator also yield the `EntityId` of each item.
+    pub fn with_id(self) -> ParIter1<WithId<T>, WithId<U>> {
+        match self {
+            Self::Tight(tight) => ParIter1::Tight(tight.with_id()),
+            Self::Update(update) => ParIter1::Update(update.with_id()),
+        }
+    }
+    /// Only yields items matching `pred`.\
+    /// With update packs only these items are flagged as modified.
+    ///
+    /// Each thread gets its own copy of `pred`.
+    pub fn filtered<P>(self, pred: P) -> ParIter1<Filter<T, P>, Filter<U, P>>
+    where
+        P: FnMut(&T::Item) -> bool + Clone + Send,
+    {
+        match self {
+            Self::Tight(tight) => ParIter1::Tight(tight.filtered(pred)),
+            Self::Update(update) => ParIter1::Update(update.filtered(pred)),
+        }
+    }
+}
+
+impl<T, U> ParallelIterator for ParIter1<T, U>
+where
+    ParTight<T>: ParallelIterator,
+    ParUpdate<U>: ParallelIterator<Item = <ParTight<T> as ParallelIterator>::Item>,
+{
+    type Item = <ParTight<T> as ParallelIterator>::Item;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        match self {
+            Self::Tight(tight) => tight.drive_unindexed(consumer),
+            Self::Update(update) => update.drive_unindexed(consumer),
+        }
+    }
+    fn opt_len(&self) -> Option<usize> {
+        match self {
+            Self::Tight(tight) => tight.opt_len(),
+            Self::Update(update) => update.opt_len(),
+        }
+    }
+}
+
+/// Parallel iterator over multiple storages.
+///
+/// This enum allows to abstract away what kind of iterator you really get.
+pub enum ParIter<T, L, U, N> {
+    Tight(ParTight<T>),
+    Loose(ParLoose<L>),
+    Update(ParUpdate<U>),
+    NonPacked(ParNonPacked<N>),
+}
+
+impl<T, L, U, N> ParIter<T, L, U, N>
+where
+    T: SplitShiperator + CurrentId,
+    L: SplitShiperator<Item = T::Item> + CurrentId<Id = T::Id>,
+    U: UpdateShiperator<Item = T::Item>,
+    N: SplitShiperator<Item = T::Item> + CurrentId<Id = T::Id>,
+{
+    /// Makes the iterator also yield the `EntityId` of each item.
+    pub fn with_id(self) -> ParIter<WithId<T>, WithId<L>, WithId<U>, WithId<N>> {
+        match self {
+            Self::Tight(tight) => ParIter::Tight(tight.with_id()),
+            Self::Loose(loose) => ParIter::Loose(loose.with_id()),
+            Self::Update(update) => ParIter::Update(update.with_id()),
+            Self::NonPacked(non_packed) => ParIter::NonPacked(non_packed.with_id()),
+        }
+    }
+    /// Only yields items matching `pred`.\
+    /// With update packs only these items are flagged as modified.
+    ///
+    /// Each thread gets its own copy of `pred`.
+    #[allow(clippy::type_complexity)]
+    pub fn filtered<P>(
+        self,
+        pred: P,
+    ) -> ParIter<Filter<T, P>, Filter<L, P>, Filter<U, P>, Filter<N, P>>
+    where
+        P: FnMut(&T::Item) -> bool + Clone + Send,
+    {
+        match self {
+            Self::Tight(tight) => ParIter::Tight(tight.filtered(pred)),
+            Self::Loose(loose) => ParIter::Loose(loose.filtered(pred)),
+            Self::Update(update) => ParIter::Update(update.filtered(pred)),
+            Self::NonPacked(non_packed) => ParIter::NonPacked(non_packed.filtered(pred)),
+        }
+    }
+}
+
+impl<T, L, U, N> ParallelIterator for ParIter<T, L, U, N>
+where
+    ParTight<T>: ParallelIterator,
+    ParLoose<L>: ParallelIterator<Item = <ParTight<T> as ParallelIterator>::Item>,
+    ParUpdate<U>: ParallelIterator<Item = <ParTight<T> as ParallelIterator>::Item>,
+    ParNonPacked<N>: ParallelIterator<Item = <ParTight<T> as ParallelIterator>::Item>,
+{
+    type Item = <ParTight<T> as ParallelIterator>::Item;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        match self {
+            Self::Tight(tight) => tight.drive_unindexed(consumer),
+            Self::Loose(loose) => loose.drive_unindexed(consumer),
+            Self::Update(update) => update.drive_unindexed(consumer),
+            Self::NonPacked(non_packed) => non_packed.drive_unindexed(consumer),
+        }
+    }
+    fn opt_len(&self) -> Option<usize> {
+        match self {
+            Self::Tight(tight) => tight.opt_len(),
+            Self::Loose(loose) => loose.opt_len(),
+            Self::Update(update) => update.opt_len(),
+            Self::NonPacked(non_packed) => non_packed.opt_len(),
+        }
+    }
+}
+
+macro_rules! par_iters {
+    ($($par_iter: ident $tight: ident $loose: ident $update: ident $non_packed: ident ($($type: ident),+);)+) => {
+        $(
+            #[doc = "Parallel iterator returned by `par_iter` on a tuple of storages."]
+            pub type $par_iter<$($type),+> = ParIter<
+                $tight<$($type),+>,
+                $loose<$($type),+>,
+                $update<$($type),+>,
+                $non_packed<$($type),+>,
+            >;
+        )+
+    }
+}
+
+par_iters![
+    ParIter2 Tight2 Loose2 Update2 NonPacked2 (A, B);
+    ParIter3 Tight3 Loose3 Update3 NonPacked3 (A, B, C);
+    ParIter4 Tight4 Loose4 Update4 NonPacked4 (A, B, C, D);
+    ParIter5 Tight5 Loose5 Update5 NonPacked5 (A, B, C, D, E);
+    ParIter6 Tight6 Loose6 Update6 NonPacked6 (A, B, C, D, E, F);
+    ParIter7 Tight7 Loose7 Update7 NonPacked7 (A, B, C, D, E, F, G);
+    ParIter8 Tight8 Loose8 Update8 NonPacked8 (A, B, C, D, E, F, G, H);
+    ParIter9 Tight9 Loose9 Update9 NonPacked9 (A, B, C, D, E, F, G, H, I);
+    ParIter10 Tight10 Loose10 Update10 NonPacked10 (A, B, C, D, E, F, G, H, I, J);
+];
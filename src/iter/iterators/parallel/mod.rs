@@ -0,0 +1,48 @@
+mod iter;
+mod producer;
+mod split;
+mod update;
+
+use super::{CurrentId, DoubleEndedShiperator, ExactSizeShiperator, Shiperator};
+use crate::iter::ShipIter;
+use crate::EntityId;
+
+pub use iter::{
+    ParIter, ParIter1, ParIter10, ParIter2, ParIter3, ParIter4, ParIter5, ParIter6, ParIter7,
+    ParIter8, ParIter9,
+};
+pub use split::{ParLoose, ParNonPacked, ParTight};
+pub use update::ParUpdate;
+
+/// Shiperator which can be divided to be iterated by multiple threads.
+///
+/// # Safety
+///
+/// The two halves returned by `split_at` have to be usable from different threads at the same time.
+#[doc(hidden)]
+pub unsafe trait SplitShiperator: Shiperator + Sized {
+    /// Splits the remaining positions in two, the first half has `index` of them.\
+    /// For exact size shiperators it's the number of items.
+    fn split_at(self, index: usize) -> (Self, Self);
+}
+
+/// Shiperator over an update pack, flagging components can be separated from the iteration.
+///
+/// Flagging moves components around, it can't happen while multiple threads iterate.
+/// `first_pass` is used to find the entities the shiperator would yield,
+/// then they're flagged one at a time and their components are fetched with `item_of`.
+///
+/// # Safety
+///
+/// `item_of` has to return the same item `post_process` would.
+#[doc(hidden)]
+pub unsafe trait UpdateShiperator: SplitShiperator + CurrentId<Id = EntityId> {
+    /// # Safety
+    ///
+    /// `entity` has to be one of the entities the shiperator yields.
+    unsafe fn mark_id(&mut self, entity: EntityId);
+    /// # Safety
+    ///
+    /// `entity` has to be one of the entities the shiperator yields and it has to be flagged already.
+    unsafe fn item_of(&mut self, entity: EntityId) -> Self::Item;
+}
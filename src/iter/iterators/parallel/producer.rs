@@ -0,0 +1,130 @@
+use super::{
+    DoubleEndedShiperator, ExactSizeShiperator, ShipIter, SplitShiperator, UpdateShiperator,
+};
+use crate::EntityId;
+use rayon::iter::plumbing::{Folder, Producer, UnindexedProducer};
+
+/// Splits shiperators knowing their exact length, it allows rayon's indexed adapters.
+pub(super) struct ExactProducer<S>(pub(super) S);
+
+// SAFE SplitShiperator guarantees both halves can be used from different threads
+unsafe impl<S: SplitShiperator> Send for ExactProducer<S> {}
+
+impl<S> Producer for ExactProducer<S>
+where
+    S: SplitShiperator + DoubleEndedShiperator + ExactSizeShiperator,
+    S::Item: Send,
+{
+    type Item = S::Item;
+    type IntoIter = ShipIter<S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iterator()
+    }
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.0.split_at(index);
+        (ExactProducer(left), ExactProducer(right))
+    }
+}
+
+/// Splits shiperators in half until they're too small, their exact length isn't known.
+pub(super) struct SplitProducer<S>(pub(super) S);
+
+// SAFE SplitShiperator guarantees both halves can be used from different threads
+unsafe impl<S: SplitShiperator> Send for SplitProducer<S> {}
+
+impl<S: SplitShiperator> UnindexedProducer for SplitProducer<S>
+where
+    S::Item: Send,
+{
+    type Item = S::Item;
+
+    fn split(self) -> (Self, Option<Self>) {
+        let len = self.0.size_hint().1.unwrap_or(0);
+        if len > 1 {
+            let (left, right) = self.0.split_at(len / 2);
+            (SplitProducer(left), Some(SplitProducer(right)))
+        } else {
+            (self, None)
+        }
+    }
+    fn fold_with<F: Folder<Self::Item>>(self, folder: F) -> F {
+        folder.consume_iter(self.0.into_iterator())
+    }
+}
+
+/// Yields the id of the entities an update shiperator would yield, without flagging them.
+pub(super) struct IdProducer<S>(pub(super) S);
+
+// SAFE SplitShiperator guarantees both halves can be used from different threads
+unsafe impl<S: SplitShiperator> Send for IdProducer<S> {}
+
+impl<S: UpdateShiperator> UnindexedProducer for IdProducer<S> {
+    type Item = EntityId;
+
+    fn split(self) -> (Self, Option<Self>) {
+        let len = self.0.size_hint().1.unwrap_or(0);
+        if len > 1 {
+            let (left, right) = self.0.split_at(len / 2);
+            (IdProducer(left), Some(IdProducer(right)))
+        } else {
+            (self, None)
+        }
+    }
+    fn fold_with<F: Folder<Self::Item>>(mut self, mut folder: F) -> F {
+        // SAFE items are dropped right away and never flagged, flagging is done separately
+        while unsafe { self.0.first_pass() }.is_some() {
+            folder = folder.consume(unsafe { self.0.current_id() });
+            if folder.full() {
+                break;
+            }
+        }
+        folder
+    }
+}
+
+/// Yields the components of flagged entities.
+pub(super) struct ItemProducer<'a, S> {
+    pub(super) ids: &'a [EntityId],
+    pub(super) iter: S,
+}
+
+// SAFE SplitShiperator guarantees both halves can be used from different threads
+unsafe impl<S: SplitShiperator> Send for ItemProducer<'_, S> {}
+
+impl<S: UpdateShiperator> UnindexedProducer for ItemProducer<'_, S>
+where
+    S::Item: Send,
+{
+    type Item = S::Item;
+
+    fn split(self) -> (Self, Option<Self>) {
+        if self.ids.len() > 1 {
+            let (left_ids, right_ids) = self.ids.split_at(self.ids.len() / 2);
+            // item_of doesn't care about the range, split_at only duplicates the views
+            let (left, right) = self.iter.split_at(0);
+            (
+                ItemProducer {
+                    ids: left_ids,
+                    iter: left,
+                },
+                Some(ItemProducer {
+                    ids: right_ids,
+                    iter: right,
+                }),
+            )
+        } else {
+            (self, None)
+        }
+    }
+    fn fold_with<F: Folder<Self::Item>>(mut self, mut folder: F) -> F {
+        for &id in self.ids {
+            // SAFE ids are all flagged and each one is only in a single producer
+            folder = folder.consume(unsafe { self.iter.item_of(id) });
+            if folder.full() {
+                break;
+            }
+        }
+        folder
+    }
+}
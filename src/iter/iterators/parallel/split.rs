@@ -0,0 +1,90 @@
+use super::producer::{ExactProducer, SplitProducer};
+use super::{DoubleEndedShiperator, ExactSizeShiperator, SplitShiperator};
+use crate::iter::filter::Filter;
+use crate::iter::with_id::WithId;
+use crate::iter::CurrentId;
+use rayon::iter::plumbing::{
+    bridge, bridge_unindexed, Consumer, ProducerCallback, UnindexedConsumer,
+};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+macro_rules! impl_split {
+    ($(#[$doc: meta])* $par: ident) => {
+        $(#[$doc])*
+        pub struct $par<S>(pub(crate) S);
+
+        impl<S: SplitShiperator> $par<S> {
+            /// Makes the iterator also yield the `EntityId` of each item.
+            pub fn with_id(self) -> $par<WithId<S>>
+            where
+                S: CurrentId,
+            {
+                $par(self.0.with_id())
+            }
+            /// Only yields items matching `pred`.
+            ///
+            /// Each thread gets its own copy of `pred`.
+            pub fn filtered<P>(self, pred: P) -> $par<Filter<S, P>>
+            where
+                P: FnMut(&S::Item) -> bool + Clone + Send,
+            {
+                $par(self.0.filter(pred))
+            }
+        }
+
+        // SAFE SplitShiperator guarantees it can be used from another thread
+        unsafe impl<S: SplitShiperator> Send for $par<S> {}
+
+        impl<S: SplitShiperator> ParallelIterator for $par<S>
+        where
+            S::Item: Send,
+        {
+            type Item = S::Item;
+
+            fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+                bridge_unindexed(SplitProducer(self.0), consumer)
+            }
+            fn opt_len(&self) -> Option<usize> {
+                match self.0.size_hint() {
+                    (lower, Some(upper)) if lower == upper => Some(lower),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<S> IndexedParallelIterator for $par<S>
+        where
+            S: SplitShiperator + DoubleEndedShiperator + ExactSizeShiperator,
+            S::Item: Send,
+        {
+            fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+                bridge(self, consumer)
+            }
+            fn len(&self) -> usize {
+                ExactSizeShiperator::len(&self.0)
+            }
+            fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+                callback.callback(ExactProducer(self.0))
+            }
+        }
+    }
+}
+
+impl_split![
+    /// Parallel iterator over tightly packed components.
+    ///
+    /// Its length is known, it can be used with rayon's indexed adapters like `zip` or `enumerate`.
+    ParTight
+];
+impl_split![
+    /// Parallel iterator over loosely packed components.
+    ///
+    /// Its length is known, it can be used with rayon's indexed adapters like `zip` or `enumerate`.
+    ParLoose
+];
+impl_split![
+    /// Parallel iterator over components not packed together.
+    ///
+    /// Entities missing a component are skipped, the exact length isn't known.
+    ParNonPacked
+];
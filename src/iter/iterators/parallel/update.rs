@@ -0,0 +1,71 @@
+use super::producer::{IdProducer, ItemProducer};
+use super::UpdateShiperator;
+use crate::iter::filter::Filter;
+use crate::iter::parallel_buffer::ParBuf;
+use crate::iter::with_id::WithId;
+use rayon::iter::plumbing::{bridge_unindexed, UnindexedConsumer};
+use rayon::iter::ParallelIterator;
+
+/// Parallel iterator over components with at least one update packed storage.
+///
+/// Flagging components as modified can't be done by multiple threads.
+/// The entities to yield are first found in parallel, then flagged,
+/// then their components are yielded in parallel.\
+/// `filtered` runs before flagging, unlike rayon's `filter` which would flag all components.
+pub struct ParUpdate<S>(pub(crate) S);
+
+impl<S: UpdateShiperator> ParUpdate<S> {
+    /// Makes the iterator also yield the `EntityId` of each item.
+    pub fn with_id(self) -> ParUpdate<WithId<S>> {
+        ParUpdate(self.0.with_id())
+    }
+    /// Only yields and flags items matching `pred`.
+    ///
+    /// Each thread gets its own copy of `pred`.
+    pub fn filtered<P>(self, pred: P) -> ParUpdate<Filter<S, P>>
+    where
+        P: FnMut(&S::Item) -> bool + Clone + Send,
+    {
+        ParUpdate(self.0.filter(pred))
+    }
+}
+
+// SAFE UpdateShiperator guarantees it can be used from another thread
+unsafe impl<S: UpdateShiperator> Send for ParUpdate<S> {}
+
+/// Parallel iterator over the entities an update shiperator would yield.
+struct ParIds<S>(S);
+
+// SAFE UpdateShiperator guarantees it can be used from another thread
+unsafe impl<S: UpdateShiperator> Send for ParIds<S> {}
+
+impl<S: UpdateShiperator> ParallelIterator for ParIds<S> {
+    type Item = crate::EntityId;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge_unindexed(IdProducer(self.0), consumer)
+    }
+}
+
+impl<S: UpdateShiperator> ParallelIterator for ParUpdate<S>
+where
+    S::Item: Send,
+{
+    type Item = S::Item;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        // views is only used to flag and fetch components, its range is empty
+        let (mut views, iter) = self.0.split_at(0);
+
+        let buffer = ParBuf::new(iter.size_hint().1.unwrap_or(0));
+        ParIds(iter).for_each(|id| buffer.push(id));
+        let ids = buffer.as_slice();
+
+        for &id in ids {
+            // SAFE ids come from the shiperator and no thread is iterating
+            unsafe { views.mark_id(id) };
+        }
+
+        bridge_unindexed(ItemProducer { ids, iter: views }, consumer)
+    }
+}
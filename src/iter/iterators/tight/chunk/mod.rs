@@ -1,7 +1,7 @@
 pub(super) mod multiple;
 mod single;
 
-use super::{AbstractMut, IntoAbstract, Shiperator};
+use super::{AbstractMut, ExactSizeShiperator, IntoAbstract, Shiperator};
 
 pub use multiple::*;
 pub use single::Chunk1;
@@ -1,4 +1,4 @@
-use super::{AbstractMut, IntoAbstract, Shiperator};
+use super::{AbstractMut, ExactSizeShiperator, IntoAbstract, Shiperator};
 
 macro_rules! impl_iterators {
     (
@@ -34,7 +34,15 @@ macro_rules! impl_iterators {
             unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
                 item
             }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.end - self.current;
+                // the last chunk can be shorter than `step`
+                let len = remaining / self.step + (remaining % self.step).min(1);
+                (len, Some(len))
+            }
         }
+
+        impl<$($type: IntoAbstract),+> ExactSizeShiperator for $chunk<$($type),+> {}
     }
 }
 
@@ -1,4 +1,4 @@
-use super::{AbstractMut, IntoAbstract, Shiperator};
+use super::{AbstractMut, ExactSizeShiperator, IntoAbstract, Shiperator};
 
 pub struct Chunk1<T: IntoAbstract> {
     pub(crate) data: T::AbsView,
@@ -25,4 +25,12 @@ impl<T: IntoAbstract> Shiperator for Chunk1<T> {
     unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
         item
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+        // the last chunk can be shorter than `step`
+        let len = remaining / self.step + (remaining % self.step).min(1);
+        (len, Some(len))
+    }
 }
+
+impl<T: IntoAbstract> ExactSizeShiperator for Chunk1<T> {}
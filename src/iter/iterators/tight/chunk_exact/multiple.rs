@@ -1,4 +1,4 @@
-use super::{AbstractMut, IntoAbstract, Shiperator};
+use super::{AbstractMut, ExactSizeShiperator, IntoAbstract, Shiperator};
 
 macro_rules! impl_iterators {
     (
@@ -32,8 +32,14 @@ macro_rules! impl_iterators {
             unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
                 item
             }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = (self.end - self.current) / self.step;
+                (len, Some(len))
+            }
         }
 
+        impl<$($type: IntoAbstract),+> ExactSizeShiperator for $chunk_exact<$($type),+> {}
+
         impl<$($type: IntoAbstract),+> $chunk_exact<$($type),+> {
             pub fn remainder(&mut self) -> ($(<$type::AbsView as AbstractMut>::Slice,)+) {
                 let end = self.end;
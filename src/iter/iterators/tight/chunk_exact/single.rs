@@ -1,4 +1,4 @@
-use super::{AbstractMut, IntoAbstract, Shiperator};
+use super::{AbstractMut, ExactSizeShiperator, IntoAbstract, Shiperator};
 
 pub struct ChunkExact1<T: IntoAbstract> {
     pub(crate) data: T::AbsView,
@@ -31,4 +31,10 @@ impl<T: IntoAbstract> Shiperator for ChunkExact1<T> {
     unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
         item
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.current) / self.step;
+        (len, Some(len))
+    }
 }
+
+impl<T: IntoAbstract> ExactSizeShiperator for ChunkExact1<T> {}
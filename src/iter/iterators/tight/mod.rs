@@ -3,7 +3,11 @@ mod chunk_exact;
 mod multiple;
 mod single;
 
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::SplitShiperator;
+use super::{
+    AbstractMut, CurrentId, DoubleEndedShiperator, ExactSizeShiperator, IntoAbstract, Shiperator,
+};
 
 pub use chunk::*;
 pub use chunk_exact::*;
@@ -1,6 +1,10 @@
 use super::chunk::multiple::*;
 use super::chunk_exact::multiple::*;
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::SplitShiperator;
+use super::{
+    AbstractMut, CurrentId, DoubleEndedShiperator, ExactSizeShiperator, IntoAbstract, Shiperator,
+};
 use crate::EntityId;
 
 macro_rules! impl_iterators {
@@ -18,6 +22,8 @@ macro_rules! impl_iterators {
             pub(crate) data: ($($type::AbsView,)+),
             pub(crate) current: usize,
             pub(crate) end: usize,
+            // index of the last item returned, from either end
+            pub(crate) last: usize,
         }
 
         impl<$($type: IntoAbstract),+> $tight<$($type),+> {
@@ -46,6 +52,7 @@ macro_rules! impl_iterators {
                 let current = self.current;
                 if current < self.end {
                     self.current += 1;
+                    self.last = current;
                     Some(($(self.data.$index.get_data(current),)+))
                 } else {
                     None
@@ -54,13 +61,51 @@ macro_rules! impl_iterators {
             unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
                 item
             }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.end - self.current;
+                (len, Some(len))
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> DoubleEndedShiperator for $tight<$($type),+> {
+            unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+                if self.current < self.end {
+                    self.end -= 1;
+                    self.last = self.end;
+                    Some(($(self.data.$index.get_data(self.end),)+))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> ExactSizeShiperator for $tight<$($type),+> {}
+
+        // SAFE each half reads and writes its own range of components
+        #[cfg(feature = "parallel")]
+        unsafe impl<$($type: IntoAbstract),+> SplitShiperator for $tight<$($type),+> {
+            fn split_at(self, index: usize) -> (Self, Self) {
+                let left = $tight {
+                    data: self.data.clone(),
+                    current: self.current,
+                    end: self.current + index,
+                    last: 0,
+                };
+                let right = $tight {
+                    data: self.data,
+                    current: self.current + index,
+                    end: self.end,
+                    last: 0,
+                };
+                (left, right)
+            }
         }
 
         impl<$($type: IntoAbstract),+> CurrentId for $tight<$($type),+> {
             type Id = EntityId;
 
             unsafe fn current_id(&self) -> Self::Id {
-                self.data.0.id_at(self.current - 1)
+                self.data.0.id_at(self.last)
             }
         }
 
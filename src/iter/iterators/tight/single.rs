@@ -1,10 +1,17 @@
-use super::{AbstractMut, Chunk1, ChunkExact1, CurrentId, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::SplitShiperator;
+use super::{
+    AbstractMut, Chunk1, ChunkExact1, CurrentId, DoubleEndedShiperator, ExactSizeShiperator,
+    IntoAbstract, Shiperator,
+};
 use crate::EntityId;
 
 pub struct Tight1<T: IntoAbstract> {
     pub(crate) data: T::AbsView,
     pub(crate) current: usize,
     pub(crate) end: usize,
+    // index of the last item returned, from either end
+    pub(crate) last: usize,
 }
 
 impl<T: IntoAbstract> Tight1<T> {
@@ -33,6 +40,7 @@ impl<T: IntoAbstract> Shiperator for Tight1<T> {
         let current = self.current;
         if current < self.end {
             self.current += 1;
+            self.last = current;
             let data = self.data.get_data(current);
             Some(data)
         } else {
@@ -42,12 +50,50 @@ impl<T: IntoAbstract> Shiperator for Tight1<T> {
     unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
         item
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<T: IntoAbstract> DoubleEndedShiperator for Tight1<T> {
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+        if self.current < self.end {
+            self.end -= 1;
+            self.last = self.end;
+            Some(self.data.get_data(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: IntoAbstract> ExactSizeShiperator for Tight1<T> {}
+
+// SAFE each half reads and writes its own range of components
+#[cfg(feature = "parallel")]
+unsafe impl<T: IntoAbstract> SplitShiperator for Tight1<T> {
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let left = Tight1 {
+            data: self.data.clone(),
+            current: self.current,
+            end: self.current + index,
+            last: 0,
+        };
+        let right = Tight1 {
+            data: self.data,
+            current: self.current + index,
+            end: self.end,
+            last: 0,
+        };
+        (left, right)
+    }
 }
 
 impl<T: IntoAbstract> CurrentId for Tight1<T> {
     type Id = EntityId;
 
     unsafe fn current_id(&self) -> Self::Id {
-        self.data.id_at(self.current - 1)
+        self.data.id_at(self.last)
     }
 }
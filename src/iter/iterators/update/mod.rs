@@ -1,7 +1,12 @@
 mod multiple;
 mod single;
 
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+use super::{
+    AbstractMut, Chunk1, ChunkExact1, CurrentId, DoubleEndedShiperator, ExactSizeShiperator,
+    IntoAbstract, Shiperator,
+};
+#[cfg(feature = "parallel")]
+use super::{SplitShiperator, UpdateShiperator};
 
 pub use multiple::*;
 pub use single::Update1;
@@ -1,4 +1,6 @@
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+use super::{AbstractMut, CurrentId, DoubleEndedShiperator, IntoAbstract, Shiperator};
+#[cfg(feature = "parallel")]
+use super::{SplitShiperator, UpdateShiperator};
 use crate::EntityId;
 
 macro_rules! impl_iterators {
@@ -14,6 +16,23 @@ macro_rules! impl_iterators {
             pub(crate) end: usize,
             pub(crate) array: usize,
             pub(crate) current_id: EntityId,
+            // set once all remaining components have been flagged, see first_pass_back
+            pub(crate) flagged_remaining: bool,
+        }
+
+        impl<$($type: IntoAbstract),+> $update<$($type),+> {
+            // going in order only ever swaps components already visited
+            unsafe fn flag_remaining(&mut self) {
+                for current in self.current..self.end {
+                    let index = std::ptr::read(self.indices.add(current));
+                    if $(self.data.$index.index_of(index).is_some())&&+ {
+                        $(
+                            self.data.$index.mark_id_modified(index);
+                        )+
+                    }
+                }
+                self.flagged_remaining = true;
+            }
         }
 
         impl<$($type: IntoAbstract),+> Shiperator for $update<$($type),+> {
@@ -44,6 +63,77 @@ macro_rules! impl_iterators {
             unsafe fn post_process(&mut self, _: Self::Item) -> Self::Item {
                 ($(self.data.$index.mark_id_modified(self.current_id),)+)
             }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, Some(self.end - self.current))
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> DoubleEndedShiperator for $update<$($type),+> {
+            unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+                // flagging a component from the back would swap it with one not visited yet
+                // and move the latter out of range, flagging in order never moves a component
+                if !self.flagged_remaining {
+                    self.flag_remaining();
+                }
+                while self.current < self.end {
+                    self.end -= 1;
+                    // SAFE same as first_pass
+                    let index = std::ptr::read(self.indices.add(self.end));
+                    let data_indices = ($(
+                        if $index == self.array {
+                            self.end
+                        } else {
+                            if let Some(index) = self.data.$index.index_of(index) {
+                                index
+                            } else {
+                                continue
+                            }
+                        },
+                    )+);
+                    self.current_id = index;
+                    return Some(($(self.data.$index.get_data(data_indices.$index),)+))
+                }
+                None
+            }
+        }
+
+        // SAFE each half reads and writes the components of its own range of entities
+        // and indices is only read
+        #[cfg(feature = "parallel")]
+        unsafe impl<$($type: IntoAbstract),+> SplitShiperator for $update<$($type),+> {
+            fn split_at(self, index: usize) -> (Self, Self) {
+                let left = $update {
+                    data: self.data.clone(),
+                    indices: self.indices,
+                    current: self.current,
+                    end: self.current + index,
+                    array: self.array,
+                    current_id: self.current_id,
+                    flagged_remaining: self.flagged_remaining,
+                };
+                let right = $update {
+                    data: self.data,
+                    indices: self.indices,
+                    current: self.current + index,
+                    end: self.end,
+                    array: self.array,
+                    current_id: self.current_id,
+                    flagged_remaining: self.flagged_remaining,
+                };
+                (left, right)
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        unsafe impl<$($type: IntoAbstract),+> UpdateShiperator for $update<$($type),+> {
+            unsafe fn mark_id(&mut self, entity: EntityId) {
+                $(
+                    self.data.$index.mark_id_modified(entity);
+                )+
+            }
+            unsafe fn item_of(&mut self, entity: EntityId) -> Self::Item {
+                ($(self.data.$index.get_data(self.data.$index.index_of_unchecked(entity)),)+)
+            }
         }
 
         impl<$($type: IntoAbstract),+> CurrentId for $update<$($type),+> {
@@ -1,4 +1,9 @@
-use super::{AbstractMut, CurrentId, IntoAbstract, Shiperator};
+use super::{
+    AbstractMut, Chunk1, ChunkExact1, CurrentId, DoubleEndedShiperator, ExactSizeShiperator,
+    IntoAbstract, Shiperator,
+};
+#[cfg(feature = "parallel")]
+use super::{SplitShiperator, UpdateShiperator};
 use crate::EntityId;
 
 pub struct Update1<T: IntoAbstract> {
@@ -6,6 +11,43 @@ pub struct Update1<T: IntoAbstract> {
     pub(crate) current: usize,
     pub(crate) end: usize,
     pub(crate) current_id: EntityId,
+    // set once all remaining components have been flagged, see first_pass_back
+    pub(crate) flagged_remaining: bool,
+}
+
+impl<T: IntoAbstract> Update1<T> {
+    /// Flags all remaining components as modified and returns slices of `step` components.\
+    /// Works best on `inserted` and `modified` windows, the components there are already flagged.
+    pub fn into_chunk(mut self, step: usize) -> Chunk1<T> {
+        self.flag_remaining();
+        Chunk1 {
+            data: self.data,
+            current: self.current,
+            end: self.end,
+            step,
+        }
+    }
+    /// Flags all remaining components as modified and returns slices of exactly `step` components.\
+    /// Works best on `inserted` and `modified` windows, the components there are already flagged.
+    pub fn into_chunk_exact(mut self, step: usize) -> ChunkExact1<T> {
+        self.flag_remaining();
+        ChunkExact1 {
+            data: self.data,
+            current: self.current,
+            end: self.end,
+            step,
+        }
+    }
+    // everything before current is already flagged so going in order never moves a component
+    // and the remaining ones stay contiguous
+    fn flag_remaining(&mut self) {
+        for index in self.current..self.end {
+            unsafe {
+                self.data.mark_modified(index);
+            }
+        }
+        self.flagged_remaining = true;
+    }
 }
 
 impl<T: IntoAbstract> Shiperator for Update1<T> {
@@ -24,6 +66,61 @@ impl<T: IntoAbstract> Shiperator for Update1<T> {
     unsafe fn post_process(&mut self, _: Self::Item) -> Self::Item {
         self.data.mark_id_modified(self.current_id)
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<T: IntoAbstract> DoubleEndedShiperator for Update1<T> {
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+        // flagging a component from the back would swap it with one not visited yet
+        // and move the latter out of range, flagging in order never moves a component
+        if !self.flagged_remaining {
+            self.flag_remaining();
+        }
+        if self.current < self.end {
+            self.end -= 1;
+            self.current_id = self.data.id_at(self.end);
+            Some(self.data.get_data(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: IntoAbstract> ExactSizeShiperator for Update1<T> {}
+
+// SAFE each half reads and writes its own range of components
+#[cfg(feature = "parallel")]
+unsafe impl<T: IntoAbstract> SplitShiperator for Update1<T> {
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let left = Update1 {
+            data: self.data.clone(),
+            current: self.current,
+            end: self.current + index,
+            current_id: self.current_id,
+            flagged_remaining: self.flagged_remaining,
+        };
+        let right = Update1 {
+            data: self.data,
+            current: self.current + index,
+            end: self.end,
+            current_id: self.current_id,
+            flagged_remaining: self.flagged_remaining,
+        };
+        (left, right)
+    }
+}
+
+#[cfg(feature = "parallel")]
+unsafe impl<T: IntoAbstract> UpdateShiperator for Update1<T> {
+    unsafe fn mark_id(&mut self, entity: EntityId) {
+        self.data.mark_id_modified(entity);
+    }
+    unsafe fn item_of(&mut self, entity: EntityId) -> Self::Item {
+        self.data.get_data(self.data.index_of_unchecked(entity))
+    }
 }
 
 impl<T: IntoAbstract> CurrentId for Update1<T> {
@@ -1,4 +1,4 @@
-use super::{CurrentId, Shiperator};
+use super::{CurrentId, DoubleEndedShiperator, ExactSizeShiperator, Shiperator};
 
 pub struct Map<I, F> {
     iter: I,
@@ -24,8 +24,23 @@ where
     unsafe fn post_process(&mut self, item: Self::Item) -> Self::Item {
         item
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<I: DoubleEndedShiperator, R, F> DoubleEndedShiperator for Map<I, F>
+where
+    F: FnMut(I::Item) -> R,
+{
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.first_pass_back()?;
+        Some((self.f)(self.iter.post_process(item)))
+    }
+}
+
+impl<I: ExactSizeShiperator, R, F> ExactSizeShiperator for Map<I, F> where F: FnMut(I::Item) -> R {}
+
 impl<I: CurrentId, R, F> CurrentId for Map<I, F>
 where
     F: FnMut(I::Item) -> R,
@@ -1,8 +1,10 @@
 mod abstract_mut;
+mod any_of;
 mod enumerate;
 mod filter;
 mod into_abstract;
 mod into_iter;
+mod into_iterator;
 pub mod iterators;
 mod map;
 #[cfg(feature = "parallel")]
@@ -11,6 +13,7 @@ mod shiperator;
 mod with_id;
 
 pub use enumerate::Enumerate;
-pub use into_iter::IntoIter;
+pub use into_iter::{IntoIter, IntoPackedPrefix};
+pub use into_iterator::ShipIter;
 pub use iterators::*;
-pub use shiperator::{CurrentId, Shiperator};
+pub use shiperator::{CurrentId, DoubleEndedShiperator, ExactSizeShiperator, Shiperator};
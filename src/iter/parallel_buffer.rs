@@ -1,5 +1,6 @@
 use std::alloc::{alloc, dealloc, Layout};
 use std::marker::PhantomData;
+use std::ptr::NonNull;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub(super) struct ParBuf<T> {
@@ -16,12 +17,17 @@ impl<T> ParBuf<T> {
     pub(super) fn new(size: usize) -> Self {
         let layout = Layout::new::<T>();
         let layout = Layout::from_size_align(layout.size() * size, layout.align()).unwrap();
-        let ptr = unsafe { alloc(layout) };
+        // allocating zero bytes isn't allowed
+        let ptr = if layout.size() == 0 {
+            NonNull::dangling().as_ptr()
+        } else {
+            unsafe { alloc(layout) as *mut T }
+        };
 
         ParBuf {
             len: AtomicUsize::new(0),
             cap: size,
-            buf: ptr as _,
+            buf: ptr,
             _phantom: PhantomData,
         }
     }
@@ -30,13 +36,19 @@ impl<T> ParBuf<T> {
         assert!(index < self.cap);
         unsafe { self.buf.add(index).write(item) };
     }
+    /// Returns the pushed items, in no particular order.
+    pub(super) fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf, self.len.load(Ordering::Acquire)) }
+    }
 }
 
 impl<T> Drop for ParBuf<T> {
     fn drop(&mut self) {
         let layout = Layout::new::<T>();
         let layout = Layout::from_size_align(layout.size() * self.cap, layout.align()).unwrap();
-        unsafe { dealloc(self.buf as _, layout) };
+        if layout.size() != 0 {
+            unsafe { dealloc(self.buf as _, layout) };
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use super::enumerate::Enumerate;
 use super::filter::Filter;
+use super::into_iterator::ShipIter;
 use super::map::Map;
 use super::with_id::WithId;
 
@@ -20,6 +21,10 @@ pub trait Shiperator {
             Some(self.post_process(item))
         }
     }
+    /// Returns the bounds on the remaining length of the iterator, like `Iterator::size_hint`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
     fn for_each<F>(self, f: F)
     where
         Self: Sized,
@@ -101,6 +106,33 @@ pub trait Shiperator {
     {
         Map::new(self, f)
     }
+    /// Turns the shiperator into a regular `Iterator`, giving access to all its adapters.
+    ///
+    /// Each item goes through `post_process` as soon as the `Iterator` yields it.
+    /// With update packed storages this means every yielded component is flagged as modified,
+    /// even if a later `Iterator` adapter discards it.
+    /// Use `Shiperator::filter` before calling this method to only flag the components you keep.
+    /// Going backward over update packed storages flags all remaining components on the first call.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize,)>();
+    ///
+    /// world.run::<(EntitiesMut, &mut usize), _, _>(|(mut entities, mut usizes)| {
+    ///     entities.add_entity(&mut usizes, 0);
+    ///     entities.add_entity(&mut usizes, 1);
+    ///     entities.add_entity(&mut usizes, 2);
+    ///
+    ///     let reversed: Vec<usize> = (&usizes).iter().into_iterator().rev().copied().collect();
+    ///     assert_eq!(reversed, vec![2, 1, 0]);
+    /// });
+    /// ```
+    fn into_iterator(self) -> ShipIter<Self>
+    where
+        Self: Sized,
+    {
+        ShipIter::new(self)
+    }
     fn find<P>(&mut self, pred: P) -> Option<Self::Item>
     where
         Self: Sized,
@@ -124,6 +156,32 @@ pub trait Shiperator {
     }
 }
 
+/// A shiperator able to yield items from both ends.
+pub trait DoubleEndedShiperator: Shiperator {
+    /// # Safety
+    ///
+    /// `post_process` has to be called with its returned value.
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item>;
+    fn next_back(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let item = self.first_pass_back()?;
+            Some(self.post_process(item))
+        }
+    }
+}
+
+/// A shiperator knowing exactly how many items it will yield.
+///
+/// `size_hint` has to return the exact length.
+pub trait ExactSizeShiperator: Shiperator {
+    fn len(&self) -> usize {
+        self.size_hint().0
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 pub trait CurrentId: Shiperator {
     type Id;
 
@@ -1,4 +1,8 @@
-use super::{CurrentId, Shiperator};
+#[cfg(feature = "parallel")]
+use super::iterators::{SplitShiperator, UpdateShiperator};
+use super::{CurrentId, DoubleEndedShiperator, ExactSizeShiperator, Shiperator};
+#[cfg(feature = "parallel")]
+use crate::EntityId;
 
 pub struct WithId<I> {
     iter: I,
@@ -21,8 +25,20 @@ impl<I: CurrentId> Shiperator for WithId<I> {
         let item = self.iter.post_process(item);
         (id, item)
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<I: CurrentId + DoubleEndedShiperator> DoubleEndedShiperator for WithId<I> {
+    unsafe fn first_pass_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.first_pass_back()?;
+        Some((self.iter.current_id(), item))
+    }
+}
+
+impl<I: CurrentId + ExactSizeShiperator> ExactSizeShiperator for WithId<I> {}
+
 impl<I: CurrentId> CurrentId for WithId<I> {
     type Id = I::Id;
 
@@ -30,3 +46,21 @@ impl<I: CurrentId> CurrentId for WithId<I> {
         self.iter.current_id()
     }
 }
+
+#[cfg(feature = "parallel")]
+unsafe impl<I: CurrentId + SplitShiperator> SplitShiperator for WithId<I> {
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.iter.split_at(index);
+        (WithId::new(left), WithId::new(right))
+    }
+}
+
+#[cfg(feature = "parallel")]
+unsafe impl<I: UpdateShiperator> UpdateShiperator for WithId<I> {
+    unsafe fn mark_id(&mut self, entity: EntityId) {
+        self.iter.mark_id(entity)
+    }
+    unsafe fn item_of(&mut self, entity: EntityId) -> Self::Item {
+        (entity, self.iter.item_of(entity))
+    }
+}
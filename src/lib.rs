@@ -93,12 +93,19 @@
 
 #![deny(bare_trait_objects)]
 
+mod added;
+mod any_of;
 mod atomic_refcell;
+mod changed;
+mod commands;
 pub mod error;
+mod events;
 mod get;
+mod hierarchy;
 pub mod internal;
 mod iter;
 mod not;
+mod optional;
 pub mod prelude;
 mod remove;
 mod run;
@@ -124,5 +131,19 @@ pub struct ThreadPool;
 /// ```
 pub struct Unique<T: ?Sized>(T);
 
-pub use storage::{AllStorages, Entities, EntitiesMut, EntityId};
-pub use world::World;
+pub use commands::{Commands, CommandsView};
+pub use events::{EventReader, EventWriter, Events};
+pub use hierarchy::{
+    AncestorsIter, Child, ChildrenIter, DescendantsIter, Hierarchy, HierarchyIter, Parent,
+};
+pub use run::{Mutation, TrySystem};
+pub use sparse_set::Hooks;
+pub use storage::{
+    AllStorages, DynamicComponent, DynamicData, DynamicId, DynamicStorages, DynamicStoragesView,
+    DynamicView, DynamicViewMut, Entities, EntitiesMut, EntityId, SharedDynamicStorages,
+    StorageInfo,
+};
+pub use world::{
+    BatchInfo, BuildEntity, Conflict, EntityBuilder, Ordered, SystemInfo, SystemOrdering,
+    WorkloadBuilder, WorkloadInfo, WorkloadSystem, World,
+};
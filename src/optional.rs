@@ -0,0 +1,38 @@
+/// Used to iterate over components entities might not have.
+/// Iterators yield `Some` component when the entity has one and `None` otherwise,
+/// the entities iterated are only decided by the other storages.
+///
+/// Iterating over `Optional` storages only yields nothing.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// let world = World::new::<(usize, u32)>();
+///
+/// world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(|(mut entities, mut usizes, mut u32s)| {
+///     entities.add_entity((&mut usizes, &mut u32s), (0usize, 1u32));
+///     entities.add_entity((&mut usizes,), (2usize,));
+/// });
+///
+/// world.run::<(&usize, &mut u32), _, _>(|(usizes, mut u32s)| {
+///     let mut iter = (&usizes, Optional(&u32s)).iter();
+///     assert_eq!(iter.next(), Some((&0, Some(&1))));
+///     assert_eq!(iter.next(), Some((&2, None)));
+///     assert_eq!(iter.next(), None);
+///
+///     (&usizes, Optional(&mut u32s)).iter().for_each(|(_, u32)| {
+///         if let Some(u32) = u32 {
+///             *u32 += 1;
+///         }
+///     });
+///     assert_eq!(u32s.get(EntityId::new_from_pair(0, 0)), Some(&2));
+/// });
+/// ```
+#[derive(Copy, Clone)]
+pub struct Optional<T>(pub T);
+
+impl<T> Optional<T> {
+    /// Returns the usual `T` storage.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
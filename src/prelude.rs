@@ -1,13 +1,26 @@
+pub use crate::added::Added;
+pub use crate::any_of::AnyOf;
+pub use crate::changed::Changed;
+pub use crate::commands::Commands;
+pub use crate::events::Events;
 pub use crate::get::GetComponent;
-pub use crate::iter::{IntoIter, Shiperator};
+pub use crate::hierarchy::{Child, Hierarchy, HierarchyIter, Parent};
+pub use crate::iter::{
+    DoubleEndedShiperator, ExactSizeShiperator, IntoIter, IntoPackedPrefix, Shiperator,
+};
 pub use crate::not::Not;
+pub use crate::optional::Optional;
 pub use crate::remove::Remove;
-pub use crate::run::System;
 #[doc(hidden)]
 pub use crate::run::SystemData;
+pub use crate::run::{System, TrySystem};
+pub use crate::sparse_set::Hooks;
 pub use crate::sparse_set::{sort, sort::Sortable, View, ViewMut};
-pub use crate::storage::{AllStorages, Entities, EntitiesMut, EntityId};
-pub use crate::world::World;
+pub use crate::storage::{
+    AllStorages, DynamicComponent, DynamicStorages, Entities, EntitiesMut, EntityId,
+    SharedDynamicStorages,
+};
+pub use crate::world::{SystemOrdering, WorkloadBuilder, WorkloadSystem, World};
 pub use crate::Unique;
 #[doc(hidden)]
 #[cfg(feature = "proc")]
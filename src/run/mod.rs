@@ -1,13 +1,14 @@
 mod system;
 mod system_data;
 
-pub(crate) use system::Dispatch;
-pub use system::System;
-pub(crate) use system_data::Mutation;
+pub(crate) use system::{Dispatch, TryDispatch};
+pub use system::{System, TrySystem};
+pub use system_data::Mutation;
 pub use system_data::SystemData;
 
 use crate::atomic_refcell::AtomicRefCell;
 use crate::error;
+use crate::sparse_set::SystemTicks;
 use crate::storage::AllStorages;
 #[cfg(feature = "parallel")]
 use rayon::ThreadPool;
@@ -17,6 +18,7 @@ pub trait Run<'a> {
 
     fn try_run<R: 'static, F: FnOnce(Self::Storage) -> R>(
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
         f: F,
     ) -> Result<R, error::GetStorage>;
@@ -27,6 +29,7 @@ impl<'a, T: SystemData<'a>> Run<'a> for T {
 
     fn try_run<R, F: FnOnce(Self::Storage) -> R>(
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
         f: F,
     ) -> Result<R, error::GetStorage> {
@@ -36,11 +39,11 @@ impl<'a, T: SystemData<'a>> Run<'a> for T {
         let storage = unsafe {
             #[cfg(feature = "parallel")]
             {
-                T::try_borrow(&mut borrows, storages, thread_pool)?
+                T::try_borrow(&mut borrows, storages, ticks, thread_pool)?
             }
             #[cfg(not(feature = "parallel"))]
             {
-                T::try_borrow(&mut borrows, storages)?
+                T::try_borrow(&mut borrows, storages, ticks)?
             }
         };
 
@@ -1,6 +1,8 @@
 use super::SystemData;
 use crate::error;
+use crate::sparse_set::SystemTicks;
 use crate::world::World;
+use std::error::Error;
 
 /// Trait to define systems.
 ///
@@ -12,6 +14,10 @@ use crate::world::World;
 /// * [AllStorages] for a mutable reference to the storage of all components
 /// * [ThreadPool] for an immutable reference to the `rayon::ThreadPool` used by the [World]
 /// * [Not] can be used to filter out a component type
+/// * [Commands] to record entity and component changes applied later
+/// * [Events] to send or read events
+/// * [DynamicStorages] for the storages of components registered at runtime, without running in parallel with other systems using them
+/// * [SharedDynamicStorages] for the same storages, running in parallel with other systems using them
 ///
 /// A tuple will allow multiple references.
 /// # Example
@@ -38,20 +44,71 @@ use crate::world::World;
 /// [ThreadPool]: struct.ThreadPool.html
 /// [World]: struct.World.html
 /// [Not]: struct.Not.html
+/// [Commands]: struct.Commands.html
+/// [Events]: struct.Events.html
+/// [DynamicStorages]: struct.DynamicStorages.html
+/// [SharedDynamicStorages]: struct.SharedDynamicStorages.html
 pub trait System<'a> {
     type Data: SystemData<'a>;
     fn run(storage: <Self::Data as SystemData<'a>>::View);
 }
 
+/// Trait to define systems that can fail.
+///
+/// Errors are returned by [World::try_run_workload] alongside the name of the system.\
+/// Fallible systems are added to workloads with [WorkloadSystem::try_new].
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// #[derive(Debug)]
+/// struct TooBig;
+///
+/// impl std::fmt::Display for TooBig {
+///     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+///         fmt.write_str("Value is too big.")
+///     }
+/// }
+///
+/// impl std::error::Error for TooBig {}
+///
+/// struct Check;
+/// impl<'a> TrySystem<'a> for Check {
+///     type Data = &'a usize;
+///     type Error = TooBig;
+///     fn try_run(usizes: <Self::Data as SystemData>::View) -> Result<(), TooBig> {
+///         if usizes.iter().filter(|&&x| x > 10).next().is_some() {
+///             Err(TooBig)
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// let world = World::new::<(usize,)>();
+/// world.run::<(EntitiesMut, &mut usize), _, _>(|(mut entities, mut usizes)| {
+///     entities.add_entity((&mut usizes,), (100,));
+/// });
+///
+/// world.add_workload("Check", WorkloadSystem::try_new(Check));
+/// assert!(world.try_run_workload("Check").is_err());
+/// ```
+/// [World::try_run_workload]: struct.World.html#method.try_run_workload
+/// [WorkloadSystem::try_new]: struct.WorkloadSystem.html#method.try_new
+pub trait TrySystem<'a> {
+    type Data: SystemData<'a>;
+    type Error: Error + Send + Sync + 'static;
+    fn try_run(storage: <Self::Data as SystemData<'a>>::View) -> Result<(), Self::Error>;
+}
+
 pub(crate) trait Dispatch: Send + Sync {
-    fn try_dispatch(world: &World) -> Result<(), error::GetStorage>;
+    fn try_dispatch(world: &World, ticks: SystemTicks) -> Result<(), error::GetStorage>;
 }
 
 impl<T> Dispatch for T
 where
     T: for<'a> System<'a> + Send + Sync,
 {
-    fn try_dispatch(world: &World) -> Result<(), error::GetStorage> {
+    fn try_dispatch(world: &World, ticks: SystemTicks) -> Result<(), error::GetStorage> {
         let storages = &world.storages;
 
         let mut borrows = Vec::new();
@@ -62,12 +119,17 @@ where
                 let thread_pool = &world.thread_pool;
                 // SAFE data is dropped before borrow
                 unsafe {
-                    <T::Data as SystemData>::try_borrow(&mut borrows, &storages, &thread_pool)?
+                    <T::Data as SystemData>::try_borrow(
+                        &mut borrows,
+                        &storages,
+                        ticks,
+                        &thread_pool,
+                    )?
                 }
             }
             #[cfg(not(feature = "parallel"))]
             {
-                unsafe { <T::Data as SystemData>::try_borrow(&mut borrows, &storages)? }
+                unsafe { <T::Data as SystemData>::try_borrow(&mut borrows, &storages, ticks)? }
             }
         };
 
@@ -76,3 +138,48 @@ where
         Ok(())
     }
 }
+
+pub(crate) trait TryDispatch: Send + Sync {
+    fn try_dispatch_fallible(
+        world: &World,
+        ticks: SystemTicks,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+impl<T> TryDispatch for T
+where
+    T: for<'a> TrySystem<'a> + Send + Sync,
+{
+    fn try_dispatch_fallible(
+        world: &World,
+        ticks: SystemTicks,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let storages = &world.storages;
+
+        let mut borrows = Vec::new();
+
+        let data = {
+            #[cfg(feature = "parallel")]
+            {
+                let thread_pool = &world.thread_pool;
+                // SAFE data is dropped before borrow
+                unsafe {
+                    <T::Data as SystemData>::try_borrow(
+                        &mut borrows,
+                        &storages,
+                        ticks,
+                        &thread_pool,
+                    )?
+                }
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                unsafe { <T::Data as SystemData>::try_borrow(&mut borrows, &storages, ticks)? }
+            }
+        };
+
+        T::try_run(data)?;
+
+        Ok(())
+    }
+}
@@ -1,15 +1,19 @@
 use crate::atomic_refcell::{AtomicRefCell, Borrow, Ref, RefMut};
+use crate::commands::{CommandQueue, Commands, CommandsView};
+use crate::events::{EventQueue, EventReader, EventWriter, Events};
 use crate::not::Not;
-use crate::sparse_set::{View, ViewMut};
+use crate::sparse_set::{SystemTicks, View, ViewMut};
 use crate::storage::{
-    AllStorages, AllStoragesViewMut, Entities, EntitiesMut, EntitiesView, EntitiesViewMut,
+    AllStorages, AllStoragesViewMut, DynamicStorages, DynamicStoragesView, Entities, EntitiesMut,
+    EntitiesView, EntitiesViewMut, SharedDynamicStorages,
 };
 use crate::{error, Unique};
 #[cfg(feature = "parallel")]
 use rayon::ThreadPool;
 use std::any::{type_name, TypeId};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// How a storage is borrowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Mutation {
     Shared,
     Unique,
@@ -24,6 +28,7 @@ pub trait SystemData<'a> {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         all_storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage>;
 
@@ -36,6 +41,7 @@ impl<'a> SystemData<'a> for AllStorages {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         all_storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] _: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let (all_storages, borrow) = RefMut::destructure(
@@ -44,7 +50,7 @@ impl<'a> SystemData<'a> for AllStorages {
                 .map_err(error::GetStorage::AllStoragesBorrow)?,
         );
         borrows.push(borrow);
-        Ok(all_storages.view_mut())
+        Ok(all_storages.view_mut(ticks))
     }
 
     fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
@@ -58,6 +64,7 @@ impl<'a> SystemData<'a> for Entities {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        _: SystemTicks,
         #[cfg(feature = "parallel")] _: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let (all_storages, all_borrow) = Ref::destructure(
@@ -86,6 +93,7 @@ impl<'a> SystemData<'a> for EntitiesMut {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        _: SystemTicks,
         #[cfg(feature = "parallel")] _: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let (all_storages, all_borrow) = Ref::destructure(
@@ -108,6 +116,184 @@ impl<'a> SystemData<'a> for EntitiesMut {
     }
 }
 
+impl<'a> SystemData<'a> for DynamicStorages {
+    type View = DynamicStoragesView<'a>;
+
+    unsafe fn try_borrow(
+        borrows: &mut Vec<Borrow<'a>>,
+        storages: &'a AtomicRefCell<AllStorages>,
+        _: SystemTicks,
+        #[cfg(feature = "parallel")] _: &'a ThreadPool,
+    ) -> Result<Self::View, error::GetStorage> {
+        let (all_storages, all_borrow) = Ref::destructure(
+            storages
+                .try_borrow()
+                .map_err(error::GetStorage::AllStoragesBorrow)?,
+        );
+        let (dynamic_storages, borrow) = Ref::destructure(
+            all_storages.0[&TypeId::of::<DynamicStorages>()]
+                .dynamic_storages()
+                .map_err(|err| error::GetStorage::StorageBorrow(("DynamicStorages", err)))?,
+        );
+        borrows.push(borrow);
+        borrows.push(all_borrow);
+        Ok(dynamic_storages.view())
+    }
+
+    // The container is only borrowed immutably, each dynamic storage has its own borrow.
+    // Unique access only tells the scheduler not to run this system in parallel with other dynamic systems.
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<DynamicStorages>(), Mutation::Unique));
+    }
+}
+
+impl<'a> SystemData<'a> for SharedDynamicStorages {
+    type View = DynamicStoragesView<'a>;
+
+    unsafe fn try_borrow(
+        borrows: &mut Vec<Borrow<'a>>,
+        storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
+        #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
+    ) -> Result<Self::View, error::GetStorage> {
+        #[cfg(feature = "parallel")]
+        {
+            <DynamicStorages as SystemData>::try_borrow(borrows, storages, ticks, thread_pool)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            <DynamicStorages as SystemData>::try_borrow(borrows, storages, ticks)
+        }
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<DynamicStorages>(), Mutation::Shared));
+    }
+}
+
+impl<'a> SystemData<'a> for Commands {
+    type View = CommandsView<'a>;
+
+    unsafe fn try_borrow(
+        borrows: &mut Vec<Borrow<'a>>,
+        storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
+        #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
+    ) -> Result<Self::View, error::GetStorage> {
+        let queue = {
+            #[cfg(feature = "parallel")]
+            {
+                <Unique<&CommandQueue> as SystemData>::try_borrow(
+                    borrows,
+                    storages,
+                    ticks,
+                    thread_pool,
+                )?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                <Unique<&CommandQueue> as SystemData>::try_borrow(borrows, storages, ticks)?
+            }
+        };
+        let entities = {
+            #[cfg(feature = "parallel")]
+            {
+                <Entities as SystemData>::try_borrow(borrows, storages, ticks, thread_pool)?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                <Entities as SystemData>::try_borrow(borrows, storages, ticks)?
+            }
+        };
+        Ok(CommandsView::new(queue, entities))
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<CommandQueue>(), Mutation::Shared));
+        infos.push((TypeId::of::<Entities>(), Mutation::Shared));
+    }
+}
+
+impl<'a, T: 'static> SystemData<'a> for Events<&T> {
+    type View = EventReader<'a, T>;
+
+    unsafe fn try_borrow(
+        borrows: &mut Vec<Borrow<'a>>,
+        storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
+        #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
+    ) -> Result<Self::View, error::GetStorage> {
+        let queue = {
+            #[cfg(feature = "parallel")]
+            {
+                <Unique<&EventQueue<T>> as SystemData>::try_borrow(
+                    borrows,
+                    storages,
+                    ticks,
+                    thread_pool,
+                )?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                <Unique<&EventQueue<T>> as SystemData>::try_borrow(borrows, storages, ticks)?
+            }
+        };
+        Ok(EventReader::new(queue, ticks))
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<EventQueue<T>>(), Mutation::Shared));
+    }
+}
+
+impl<'a, T: 'static + Send + Sync> SystemData<'a> for Events<&mut T> {
+    type View = EventWriter<'a, T>;
+
+    unsafe fn try_borrow(
+        borrows: &mut Vec<Borrow<'a>>,
+        storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
+        #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
+    ) -> Result<Self::View, error::GetStorage> {
+        // events are only sent through commands but the channel has to be registered
+        // and writers don't run at the same time as other systems using it
+        #[cfg(feature = "parallel")]
+        {
+            <Unique<&mut EventQueue<T>> as SystemData>::try_borrow(
+                borrows,
+                storages,
+                ticks,
+                thread_pool,
+            )?;
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            <Unique<&mut EventQueue<T>> as SystemData>::try_borrow(borrows, storages, ticks)?;
+        }
+        let commands = {
+            #[cfg(feature = "parallel")]
+            {
+                <Unique<&CommandQueue> as SystemData>::try_borrow(
+                    borrows,
+                    storages,
+                    ticks,
+                    thread_pool,
+                )?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                <Unique<&CommandQueue> as SystemData>::try_borrow(borrows, storages, ticks)?
+            }
+        };
+        Ok(EventWriter::new(commands))
+    }
+
+    fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
+        infos.push((TypeId::of::<EventQueue<T>>(), Mutation::Unique));
+        infos.push((TypeId::of::<CommandQueue>(), Mutation::Shared));
+    }
+}
+
 #[cfg(feature = "parallel")]
 impl<'a> SystemData<'a> for crate::ThreadPool {
     type View = &'a ThreadPool;
@@ -115,6 +301,7 @@ impl<'a> SystemData<'a> for crate::ThreadPool {
     unsafe fn try_borrow(
         _: &mut Vec<Borrow<'a>>,
         _: &'a AtomicRefCell<AllStorages>,
+        _: SystemTicks,
         thread_pool: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         Ok(thread_pool)
@@ -129,6 +316,7 @@ impl<'a, T: 'static> SystemData<'a> for &T {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] _: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let (all_storages, all_borrow) = Ref::destructure(
@@ -146,7 +334,7 @@ impl<'a, T: 'static> SystemData<'a> for &T {
         );
         borrows.push(borrow);
         borrows.push(all_borrow);
-        Ok(array.view())
+        Ok(array.view_with_ticks(ticks))
     }
 
     fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
@@ -160,6 +348,7 @@ impl<'a, T: 'static> SystemData<'a> for &mut T {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] _: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let (all_storages, all_borrow) = Ref::destructure(
@@ -177,7 +366,7 @@ impl<'a, T: 'static> SystemData<'a> for &mut T {
         );
         borrows.push(borrow);
         borrows.push(all_borrow);
-        Ok(array.view_mut())
+        Ok(array.view_mut_with_ticks(ticks))
     }
 
     fn borrow_infos(infos: &mut Vec<(TypeId, Mutation)>) {
@@ -191,16 +380,17 @@ impl<'a, T: 'static> SystemData<'a> for Not<&T> {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let view = {
             #[cfg(feature = "parallel")]
             {
-                <&T as SystemData>::try_borrow(borrows, storages, thread_pool)?
+                <&T as SystemData>::try_borrow(borrows, storages, ticks, thread_pool)?
             }
             #[cfg(not(feature = "parallel"))]
             {
-                <&T as SystemData>::try_borrow(borrows, storages)?
+                <&T as SystemData>::try_borrow(borrows, storages, ticks)?
             }
         };
         Ok(Not(view))
@@ -217,16 +407,17 @@ impl<'a, T: 'static> SystemData<'a> for Not<&mut T> {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let view = {
             #[cfg(feature = "parallel")]
             {
-                <&mut T as SystemData>::try_borrow(borrows, storages, thread_pool)?
+                <&mut T as SystemData>::try_borrow(borrows, storages, ticks, thread_pool)?
             }
             #[cfg(not(feature = "parallel"))]
             {
-                <&mut T as SystemData>::try_borrow(borrows, storages)?
+                <&mut T as SystemData>::try_borrow(borrows, storages, ticks)?
             }
         };
         Ok(Not(view))
@@ -243,16 +434,17 @@ impl<'a, T: 'static> SystemData<'a> for Unique<&T> {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let view = {
             #[cfg(feature = "parallel")]
             {
-                <&T as SystemData>::try_borrow(borrows, storages, thread_pool)?
+                <&T as SystemData>::try_borrow(borrows, storages, ticks, thread_pool)?
             }
             #[cfg(not(feature = "parallel"))]
             {
-                <&T as SystemData>::try_borrow(borrows, storages)?
+                <&T as SystemData>::try_borrow(borrows, storages, ticks)?
             }
         };
 
@@ -277,16 +469,17 @@ impl<'a, T: 'static> SystemData<'a> for Unique<&mut T> {
     unsafe fn try_borrow(
         borrows: &mut Vec<Borrow<'a>>,
         storages: &'a AtomicRefCell<AllStorages>,
+        ticks: SystemTicks,
         #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
     ) -> Result<Self::View, error::GetStorage> {
         let view = {
             #[cfg(feature = "parallel")]
             {
-                <&mut T as SystemData>::try_borrow(borrows, storages, thread_pool)?
+                <&mut T as SystemData>::try_borrow(borrows, storages, ticks, thread_pool)?
             }
             #[cfg(not(feature = "parallel"))]
             {
-                <&mut T as SystemData>::try_borrow(borrows, storages)?
+                <&mut T as SystemData>::try_borrow(borrows, storages, ticks)?
             }
         };
 
@@ -313,18 +506,19 @@ macro_rules! impl_system_data {
             unsafe fn try_borrow(
                 borrows: &mut Vec<Borrow<'a>>,
                 storages: &'a AtomicRefCell<AllStorages>,
+                ticks: SystemTicks,
                 #[cfg(feature = "parallel")] thread_pool: &'a ThreadPool,
             ) -> Result<Self::View, error::GetStorage> {
                 #[cfg(feature = "parallel")]
                 {
                     Ok(($(
-                        <$type as SystemData>::try_borrow(borrows, storages, thread_pool)?,
+                        <$type as SystemData>::try_borrow(borrows, storages, ticks, thread_pool)?,
                     )+))
                 }
                 #[cfg(not(feature = "parallel"))]
                 {
                     Ok(($(
-                        <$type as SystemData>::try_borrow(borrows, storages)?,
+                        <$type as SystemData>::try_borrow(borrows, storages, ticks)?,
                     )+))
                 }
             }
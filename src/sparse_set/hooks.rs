@@ -0,0 +1,83 @@
+use crate::storage::EntityId;
+
+/// Callbacks run when `T` components enter or leave their storage.
+///
+/// - `on_insert` runs after a component is added to an entity, including when it replaces another one.
+/// - `on_remove` runs when a component is taken out of the storage and handed back:
+///   when it's removed with `Remove`, replaced, or when hierarchy links are removed.
+/// - `on_delete` runs when the storage would drop a component:
+///   when its entity is deleted, all entities are cleared or the storage is dropped with the `World`.
+///   It takes ownership of the component.
+///
+/// Hooks run while the storage is borrowed mutably.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use std::sync::Arc;
+/// let world = World::new::<(usize,)>();
+/// let released = Arc::new(AtomicUsize::new(0));
+///
+/// let counter = released.clone();
+/// world.set_hooks(Hooks::default().on_delete(move |_, handle: usize| {
+///     counter.fetch_add(handle, Ordering::Relaxed);
+/// }));
+///
+/// let entity = world.entity_builder().with(5usize).build();
+/// world.run::<AllStorages, _, _>(|mut all_storages| {
+///     all_storages.delete(entity);
+/// });
+///
+/// assert_eq!(released.load(Ordering::Relaxed), 5);
+/// ```
+pub struct Hooks<T> {
+    pub(crate) on_insert: Option<RefHook<T>>,
+    pub(crate) on_remove: Option<RefHook<T>>,
+    pub(crate) on_delete: Option<OwnedHook<T>>,
+}
+
+type RefHook<T> = Box<dyn Fn(EntityId, &mut T) + Send + Sync>;
+type OwnedHook<T> = Box<dyn Fn(EntityId, T) + Send + Sync>;
+
+impl<T> Default for Hooks<T> {
+    fn default() -> Self {
+        Hooks {
+            on_insert: None,
+            on_remove: None,
+            on_delete: None,
+        }
+    }
+}
+
+impl<T> Hooks<T> {
+    /// Sets the callback run after a component is added to an entity.
+    pub fn on_insert<F: Fn(EntityId, &mut T) + Send + Sync + 'static>(mut self, f: F) -> Self {
+        self.on_insert = Some(Box::new(f));
+        self
+    }
+    /// Sets the callback run when a component is taken out of the storage and handed back.
+    pub fn on_remove<F: Fn(EntityId, &mut T) + Send + Sync + 'static>(mut self, f: F) -> Self {
+        self.on_remove = Some(Box::new(f));
+        self
+    }
+    /// Sets the callback receiving components the storage would drop.
+    pub fn on_delete<F: Fn(EntityId, T) + Send + Sync + 'static>(mut self, f: F) -> Self {
+        self.on_delete = Some(Box::new(f));
+        self
+    }
+    pub(crate) fn inserted(&self, entity: EntityId, component: &mut T) {
+        if let Some(on_insert) = &self.on_insert {
+            on_insert(entity, component);
+        }
+    }
+    pub(crate) fn removed(&self, entity: EntityId, component: &mut T) {
+        if let Some(on_remove) = &self.on_remove {
+            on_remove(entity, component);
+        }
+    }
+    pub(crate) fn deleted(&self, entity: EntityId, component: T) {
+        if let Some(on_delete) = &self.on_delete {
+            on_delete(entity, component);
+        }
+    }
+}
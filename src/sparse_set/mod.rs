@@ -1,13 +1,24 @@
+mod hooks;
 mod pack_info;
+#[cfg(feature = "serialization")]
+mod serialization;
 pub mod sort;
+mod ticks;
 mod view;
 mod view_add_entity;
 
 use crate::storage::EntityId;
+pub use hooks::Hooks;
 pub(crate) use pack_info::{LoosePack, Pack, PackInfo, TightPack, UpdatePack};
+#[cfg(feature = "serialization")]
+pub(crate) use serialization::{check_storages, DeserializeSparseSet, SerializeSparseSet};
+use std::any::TypeId;
+use std::mem::size_of;
+pub use ticks::SystemTicks;
+pub(crate) use ticks::Ticks;
 pub(crate) use view::RawViewMut;
 pub use view::{View, ViewMut};
-pub(crate) use view_add_entity::ViewAddEntity;
+pub(crate) use view_add_entity::{ViewAddEntity, ViewBulkAddEntity};
 
 // A sparse array is a data structure with 2 vectors: one sparse, the other dense.
 // Only usize can be added. On insertion, the number is pushed into the dense vector
@@ -22,6 +33,9 @@ pub struct SparseSet<T> {
     dense: Vec<EntityId>,
     data: Vec<T>,
     pub(crate) pack_info: PackInfo<T>,
+    // None unless change tracking was enabled
+    ticks: Option<Ticks>,
+    hooks: Hooks<T>,
 }
 
 impl<T> Default for SparseSet<T> {
@@ -31,6 +45,19 @@ impl<T> Default for SparseSet<T> {
             dense: Vec::new(),
             data: Vec::new(),
             pack_info: Default::default(),
+            ticks: None,
+            hooks: Hooks::default(),
+        }
+    }
+}
+
+// Components still present are handed to the `on_delete` hook, like when their entity is deleted.
+impl<T> Drop for SparseSet<T> {
+    fn drop(&mut self) {
+        if let Some(on_delete) = &self.hooks.on_delete {
+            for (entity, component) in self.dense.drain(..).zip(self.data.drain(..)) {
+                on_delete(entity, component);
+            }
         }
     }
 }
@@ -51,7 +78,20 @@ impl<T: 'static> SparseSet<T> {
             None
         }
     }
+    /// Returns a mutable reference to the element at this index if present.\
+    /// The element isn't flagged as modified.
+    pub(crate) fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
+        if self.contains(entity) {
+            Some(unsafe {
+                self.data
+                    .get_unchecked_mut(*self.sparse.get_unchecked(entity.index()))
+            })
+        } else {
+            None
+        }
+    }
     /// Removes and returns the element at index if present.
+    #[cfg(test)]
     pub(crate) fn remove(&mut self, entity: EntityId) -> Option<T> {
         self.view_mut().remove(entity)
     }
@@ -60,21 +100,45 @@ impl<T: 'static> SparseSet<T> {
         self.data.len()
     }
     pub(crate) fn view(&self) -> View<T> {
+        self.view_with_ticks(SystemTicks::default())
+    }
+    pub(crate) fn view_mut(&mut self) -> ViewMut<T> {
+        self.view_mut_with_ticks(SystemTicks::default())
+    }
+    /// Returns a view comparing changes against `system_ticks`.
+    pub(crate) fn view_with_ticks(&self, system_ticks: SystemTicks) -> View<T> {
         View {
             sparse: &self.sparse,
             dense: &self.dense,
             data: &self.data,
             pack_info: &self.pack_info,
+            ticks: self.ticks.as_ref(),
+            system_ticks,
         }
     }
-    pub(crate) fn view_mut(&mut self) -> ViewMut<T> {
+    /// Returns a view stamping changes with `system_ticks.current`.
+    pub(crate) fn view_mut_with_ticks(&mut self, system_ticks: SystemTicks) -> ViewMut<T> {
         ViewMut {
             sparse: &mut self.sparse,
             dense: &mut self.dense,
             data: &mut self.data,
             pack_info: &mut self.pack_info,
+            ticks: &mut self.ticks,
+            hooks: &self.hooks,
+            system_ticks,
+        }
+    }
+    /// Starts recording when components are added and modified.\
+    /// Components already present are considered old.
+    pub(crate) fn track_changes(&mut self) {
+        if self.ticks.is_none() {
+            self.ticks = Some(Ticks::new(self.sparse.len()));
         }
     }
+    /// Replaces the callbacks run when components enter or leave the storage.
+    pub(crate) fn set_hooks(&mut self, hooks: Hooks<T>) {
+        self.hooks = hooks;
+    }
     //          ▼ old end of pack
     //              ▼ new end of pack
     // [_ _ _ _ | _ | _ _ _ _ _]
@@ -96,6 +160,15 @@ impl<T: 'static> SparseSet<T> {
         assert!(self.sparse.is_empty() && self.dense.is_empty() && self.data.is_empty());
         self.data.push(component)
     }
+    /// Returns the number of bytes used by the storage, heap allocations included.
+    pub(crate) fn memory_usage(&self) -> usize {
+        size_of::<Self>()
+            + self.sparse.capacity() * size_of::<usize>()
+            + self.dense.capacity() * size_of::<EntityId>()
+            + self.data.capacity() * size_of::<T>()
+            + self.pack_info.observer_types.capacity() * size_of::<TypeId>()
+            + self.ticks.as_ref().map_or(0, Ticks::memory_usage)
+    }
     /// Returns true if this storage is a unique storage.
     pub(crate) fn is_unique(&self) -> bool {
         self.view().is_unique()
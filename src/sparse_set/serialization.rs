@@ -0,0 +1,438 @@
+use super::{LoosePack, Pack, PackInfo, SparseSet, TightPack, UpdatePack};
+use crate::storage::{EntitiesView, EntityId};
+use serde::de::{self, DeserializeOwned, EnumAccess, VariantAccess, Visitor};
+use serde::ser::{self, SerializeTupleStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::TypeId;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+// TypeIds aren't stable between compilations, packs are saved with the name of the types instead.
+// `types` maps the name of all storages serialized alongside this one to their TypeId.
+pub(crate) struct SerializeSparseSet<'a, T> {
+    pub(crate) sparse_set: &'a SparseSet<T>,
+    pub(crate) types: &'a [(&'static str, TypeId)],
+}
+
+impl<T: Serialize> Serialize for SerializeSparseSet<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tup = serializer.serialize_tuple_struct("SparseSet", 4)?;
+        tup.serialize_field(&self.sparse_set.dense)?;
+        tup.serialize_field(&self.sparse_set.data)?;
+        tup.serialize_field(&SerializePack {
+            pack: &self.sparse_set.pack_info.pack,
+            types: self.types,
+        })?;
+        tup.serialize_field(&type_names::<S::Error>(
+            &self.sparse_set.pack_info.observer_types,
+            self.types,
+        )?)?;
+        tup.end()
+    }
+}
+
+struct SerializePack<'a, T> {
+    pack: &'a Pack<T>,
+    types: &'a [(&'static str, TypeId)],
+}
+
+impl<T: Serialize> Serialize for SerializePack<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.pack {
+            Pack::NoPack => serializer.serialize_unit_variant("Pack", 0, "NoPack"),
+            Pack::Tight(pack) => serializer.serialize_newtype_variant(
+                "Pack",
+                1,
+                "Tight",
+                &(type_names::<S::Error>(&pack.types, self.types)?, pack.len),
+            ),
+            Pack::Loose(pack) => serializer.serialize_newtype_variant(
+                "Pack",
+                2,
+                "Loose",
+                &(
+                    type_names::<S::Error>(&pack.tight_types, self.types)?,
+                    type_names::<S::Error>(&pack.loose_types, self.types)?,
+                    pack.len,
+                ),
+            ),
+            Pack::Update(pack) => serializer.serialize_newtype_variant(
+                "Pack",
+                3,
+                "Update",
+                &(pack.inserted, pack.modified, &pack.removed),
+            ),
+        }
+    }
+}
+
+/// Maps each `TypeId` to the name of its type.
+fn type_names<E: ser::Error>(
+    type_ids: &[TypeId],
+    types: &[(&'static str, TypeId)],
+) -> Result<Vec<&'static str>, E> {
+    type_ids
+        .iter()
+        .map(|type_id| {
+            types
+                .iter()
+                .find(|(_, id)| id == type_id)
+                .map(|(name, _)| *name)
+                .ok_or_else(|| {
+                    E::custom(
+                        "A storage is packed with a storage that isn't part of the serialization.",
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Maps each type name to its `TypeId`, the result is sorted.
+fn type_ids(names: &[String], types: &[(&'static str, TypeId)]) -> Result<Vec<TypeId>, String> {
+    let mut type_ids = names
+        .iter()
+        .map(|name| {
+            types
+                .iter()
+                .find(|(type_name, _)| type_name == name)
+                .map(|(_, type_id)| *type_id)
+                .ok_or_else(|| {
+                    format!(
+                        "{} is packed but isn't part of the deserialized storages.",
+                        name
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    type_ids.sort_unstable();
+    Ok(type_ids)
+}
+
+/// `SparseSet` as it was serialized, packs still reference types by name.
+pub(crate) struct DeserializeSparseSet<T> {
+    dense: Vec<EntityId>,
+    data: Vec<T>,
+    pack: DeserializePack<T>,
+    observer_types: Vec<String>,
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for DeserializeSparseSet<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (dense, data, pack, observer_types) = Deserialize::deserialize(deserializer)?;
+
+        Ok(DeserializeSparseSet {
+            dense,
+            data,
+            pack,
+            observer_types,
+        })
+    }
+}
+
+impl<T> DeserializeSparseSet<T> {
+    /// Rebuilds the sparse array and the pack information.
+    /// `types` maps the name of all deserialized storages to their TypeId.
+    pub(crate) fn into_sparse_set(
+        self,
+        types: &[(&'static str, TypeId)],
+    ) -> Result<SparseSet<T>, String> {
+        let DeserializeSparseSet {
+            dense,
+            data,
+            pack,
+            observer_types,
+        } = self;
+
+        // unique storages only have a single component and no entity
+        let is_unique = dense.is_empty() && data.len() == 1;
+        if dense.len() != data.len() && !is_unique {
+            return Err("A storage doesn't have as many components as entities.".to_string());
+        }
+
+        let mut sparse = Vec::new();
+        for (i, entity) in dense.iter().enumerate() {
+            if entity.index() >= sparse.len() {
+                sparse.resize(entity.index() + 1, 0);
+            }
+            sparse[entity.index()] = i;
+        }
+
+        let pack = match pack {
+            DeserializePack::NoPack => Pack::NoPack,
+            DeserializePack::Tight(types_names, len) => {
+                if len > dense.len() {
+                    return Err("A tight pack is longer than its storage.".to_string());
+                }
+                let mut pack = TightPack::new(Arc::from(type_ids(&types_names, types)?));
+                pack.len = len;
+                Pack::Tight(pack)
+            }
+            DeserializePack::Loose(tight_names, loose_names, len) => {
+                if len > dense.len() {
+                    return Err("A loose pack is longer than its storage.".to_string());
+                }
+                let mut pack = LoosePack::new(
+                    Arc::from(type_ids(&tight_names, types)?),
+                    Arc::from(type_ids(&loose_names, types)?),
+                );
+                pack.len = len;
+                Pack::Loose(pack)
+            }
+            DeserializePack::Update(inserted, modified, removed) => {
+                if inserted + modified > dense.len() {
+                    return Err("An update pack is longer than its storage.".to_string());
+                }
+                Pack::Update(UpdatePack {
+                    inserted,
+                    modified,
+                    removed,
+                })
+            }
+        };
+
+        Ok(SparseSet {
+            sparse,
+            dense,
+            data,
+            pack_info: PackInfo {
+                pack,
+                observer_types: type_ids(&observer_types, types)?,
+            },
+            ticks: None,
+            hooks: Default::default(),
+        })
+    }
+}
+
+impl<T> SparseSet<T> {
+    pub(crate) fn deserialized(&self, type_id: TypeId) -> DeserializedStorage<'_> {
+        DeserializedStorage {
+            type_id,
+            sparse: &self.sparse,
+            dense: &self.dense,
+            pack: match &self.pack_info.pack {
+                Pack::Tight(pack) => Packed::Tight(pack),
+                Pack::Loose(pack) => Packed::Loose(pack),
+                _ => Packed::Other,
+            },
+        }
+    }
+}
+
+/// Type erased part of a deserialized storage, used to check it against the other storages.
+pub(crate) struct DeserializedStorage<'a> {
+    type_id: TypeId,
+    sparse: &'a [usize],
+    dense: &'a [EntityId],
+    pack: Packed<'a>,
+}
+
+enum Packed<'a> {
+    Tight(&'a TightPack),
+    Loose(&'a LoosePack),
+    Other,
+}
+
+impl DeserializedStorage<'_> {
+    fn contains(&self, entity: EntityId) -> bool {
+        entity.index() < self.sparse.len()
+            && self.sparse[entity.index()] < self.dense.len()
+            && self.dense[self.sparse[entity.index()]] == entity
+    }
+}
+
+/// Checks the deserialized storages against each other and against the entities.\
+/// Each storage was checked on its own when it was built,
+/// this makes sure packs agree and only reference living entities.
+pub(crate) fn check_storages(
+    storages: &[DeserializedStorage<'_>],
+    entities: &EntitiesView<'_>,
+) -> Result<(), String> {
+    // all storages named by a pack are part of the deserialized storages
+    let find = |type_id: &TypeId| {
+        storages
+            .iter()
+            .find(|storage| storage.type_id == *type_id)
+            .unwrap()
+    };
+
+    for storage in storages {
+        for (i, &entity) in storage.dense.iter().enumerate() {
+            if storage.sparse[entity.index()] != i {
+                return Err("A storage has multiple components for the same entity.".to_string());
+            }
+            if !entities.is_alive(entity) {
+                return Err("A storage has a component for an entity that isn't alive.".to_string());
+            }
+        }
+
+        match storage.pack {
+            Packed::Tight(pack) => {
+                if pack.types.binary_search(&storage.type_id).is_err() {
+                    return Err("A storage is tight packed without its own type.".to_string());
+                }
+                for other in pack.types.iter().map(find) {
+                    match other.pack {
+                        Packed::Tight(other_pack)
+                            if other_pack.types == pack.types && other_pack.len == pack.len => {}
+                        _ => {
+                            return Err(
+                                "Storages of a tight pack don't agree on the pack.".to_string()
+                            )
+                        }
+                    }
+                    if other.dense[..pack.len] != storage.dense[..pack.len] {
+                        return Err(
+                            "Storages of a tight pack don't have the same packed entities."
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+            Packed::Loose(pack) => {
+                if pack.tight_types.binary_search(&storage.type_id).is_err() {
+                    return Err("A storage is loose packed without its own type.".to_string());
+                }
+                for other in pack.tight_types.iter().map(find) {
+                    match other.pack {
+                        Packed::Loose(other_pack)
+                            if other_pack.tight_types == pack.tight_types
+                                && other_pack.loose_types == pack.loose_types
+                                && other_pack.len == pack.len => {}
+                        _ => {
+                            return Err(
+                                "Storages of a loose pack don't agree on the pack.".to_string()
+                            )
+                        }
+                    }
+                    if other.dense[..pack.len] != storage.dense[..pack.len] {
+                        return Err(
+                            "Storages of a loose pack don't have the same packed entities."
+                                .to_string(),
+                        );
+                    }
+                }
+                for other in pack.loose_types.iter().map(find) {
+                    if !storage.dense[..pack.len]
+                        .iter()
+                        .all(|&entity| other.contains(entity))
+                    {
+                        return Err(
+                            "A loosely packed storage is missing some packed entities.".to_string()
+                        );
+                    }
+                }
+            }
+            Packed::Other => {}
+        }
+    }
+
+    Ok(())
+}
+
+enum DeserializePack<T> {
+    NoPack,
+    Tight(Vec<String>, usize),
+    Loose(Vec<String>, Vec<String>, usize),
+    Update(usize, usize, Vec<(EntityId, T)>),
+}
+
+const PACK_VARIANTS: &[&str] = &["NoPack", "Tight", "Loose", "Update"];
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for DeserializePack<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PackVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T: DeserializeOwned> Visitor<'de> for PackVisitor<T> {
+            type Value = DeserializePack<T>;
+
+            fn expecting(&self, fmt: &mut Formatter) -> std::fmt::Result {
+                fmt.write_str("enum Pack")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                match data.variant()? {
+                    (PackKind::NoPack, variant) => {
+                        variant.unit_variant()?;
+                        Ok(DeserializePack::NoPack)
+                    }
+                    (PackKind::Tight, variant) => {
+                        let (types, len) = variant.newtype_variant()?;
+                        Ok(DeserializePack::Tight(types, len))
+                    }
+                    (PackKind::Loose, variant) => {
+                        let (tight_types, loose_types, len) = variant.newtype_variant()?;
+                        Ok(DeserializePack::Loose(tight_types, loose_types, len))
+                    }
+                    (PackKind::Update, variant) => {
+                        let (inserted, modified, removed) = variant.newtype_variant()?;
+                        Ok(DeserializePack::Update(inserted, modified, removed))
+                    }
+                }
+            }
+        }
+
+        deserializer.deserialize_enum("Pack", PACK_VARIANTS, PackVisitor(std::marker::PhantomData))
+    }
+}
+
+enum PackKind {
+    NoPack,
+    Tight,
+    Loose,
+    Update,
+}
+
+impl<'de> Deserialize<'de> for PackKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PackKindVisitor;
+
+        impl<'de> Visitor<'de> for PackKindVisitor {
+            type Value = PackKind;
+
+            fn expecting(&self, fmt: &mut Formatter) -> std::fmt::Result {
+                fmt.write_str("a pack variant")
+            }
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                match value {
+                    0 => Ok(PackKind::NoPack),
+                    1 => Ok(PackKind::Tight),
+                    2 => Ok(PackKind::Loose),
+                    3 => Ok(PackKind::Update),
+                    _ => Err(E::invalid_value(
+                        de::Unexpected::Unsigned(value),
+                        &"variant index 0 <= i < 4",
+                    )),
+                }
+            }
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                match value {
+                    "NoPack" => Ok(PackKind::NoPack),
+                    "Tight" => Ok(PackKind::Tight),
+                    "Loose" => Ok(PackKind::Loose),
+                    "Update" => Ok(PackKind::Update),
+                    _ => Err(E::unknown_variant(value, PACK_VARIANTS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(PackKindVisitor)
+    }
+}
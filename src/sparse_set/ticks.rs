@@ -0,0 +1,106 @@
+use crate::storage::EntityId;
+use std::mem::size_of;
+
+/// Ticks between which a system looks for changes.
+///
+/// `last_run` is the tick at which the system last ran, `current` the tick of this run.\
+/// `oldest` is the `last_run` of the workload system which ran the longest time ago.\
+/// `system_id` identifies workload systems, it's `None` for `World::run`, which always starts from tick 0.
+#[derive(Clone, Copy, Default)]
+pub struct SystemTicks {
+    pub(crate) last_run: u32,
+    pub(crate) current: u32,
+    pub(crate) oldest: u32,
+    pub(crate) system_id: Option<usize>,
+}
+
+impl SystemTicks {
+    /// Returns true if `tick` happened after `last_run`.
+    ///
+    /// Comparisons are made relative to `current` to handle ticks wrapping around.
+    pub(crate) fn is_newer(self, tick: u32) -> bool {
+        self.current.wrapping_sub(tick) < self.current.wrapping_sub(self.last_run)
+    }
+    /// Returns true if all workload systems ran after `tick`.
+    pub(crate) fn is_seen_by_all(self, tick: u32) -> bool {
+        self.current.wrapping_sub(tick) >= self.current.wrapping_sub(self.oldest)
+    }
+}
+
+// Insertion and modification tick of each component.
+// Unlike `data` they're indexed by the entities' index, packing and sorting don't have to touch them.
+// Entries of entities without component are left untouched and overwritten on insertion.
+pub(crate) struct Ticks {
+    pub(crate) inserted: Vec<u32>,
+    pub(crate) modified: Vec<u32>,
+    // entities whose component was removed and when
+    // kept until all workload systems had the chance to see them or `clear_removed` is called
+    pub(crate) removed: Vec<(EntityId, u32)>,
+}
+
+impl Ticks {
+    /// Creates ticks for `len` entities, all components are considered old.
+    pub(crate) fn new(len: usize) -> Self {
+        Ticks {
+            inserted: vec![0; len],
+            modified: vec![0; len],
+            removed: Vec::new(),
+        }
+    }
+    /// Returns the number of bytes allocated on the heap.
+    pub(crate) fn memory_usage(&self) -> usize {
+        (self.inserted.capacity() + self.modified.capacity()) * size_of::<u32>()
+            + self.removed.capacity() * size_of::<(EntityId, u32)>()
+    }
+    /// Marks the component of the entity at `index` as inserted at `tick`.
+    pub(crate) fn insert(&mut self, index: usize, tick: u32) {
+        if index >= self.inserted.len() {
+            self.inserted.resize(index + 1, 0);
+            self.modified.resize(index + 1, 0);
+        }
+        self.inserted[index] = tick;
+        self.modified[index] = tick;
+    }
+    /// Marks the components of all `entities` as inserted at `tick`.
+    ///
+    /// `len` is greater than the index of all `entities`.
+    pub(crate) fn insert_many(&mut self, entities: &[EntityId], len: usize, tick: u32) {
+        if len > self.inserted.len() {
+            self.inserted.resize(len, 0);
+            self.modified.resize(len, 0);
+        }
+        for entity in entities {
+            self.inserted[entity.index()] = tick;
+            self.modified[entity.index()] = tick;
+        }
+    }
+    /// Records the removal of `entity`'s component
+    /// and forgets removals all workload systems already saw.
+    pub(crate) fn remove(&mut self, entity: EntityId, system_ticks: SystemTicks) {
+        self.forget_seen(system_ticks);
+        self.removed.push((entity, system_ticks.current));
+    }
+    /// Records the removal of all `entities`' component
+    /// and forgets removals all workload systems already saw.
+    pub(crate) fn remove_many(
+        &mut self,
+        entities: impl IntoIterator<Item = EntityId>,
+        system_ticks: SystemTicks,
+    ) {
+        self.forget_seen(system_ticks);
+        self.removed.extend(
+            entities
+                .into_iter()
+                .map(|entity| (entity, system_ticks.current)),
+        );
+    }
+    fn forget_seen(&mut self, system_ticks: SystemTicks) {
+        // removals are pushed in tick order, the ones seen by all are at the front
+        let seen = self
+            .removed
+            .iter()
+            .take_while(|&&(_, tick)| system_ticks.is_seen_by_all(tick))
+            .count();
+        self.removed.drain(..seen);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Pack, PackInfo};
+use super::{Hooks, Pack, PackInfo, SystemTicks, Ticks};
 use crate::storage::EntityId;
 use std::marker::PhantomData;
 
@@ -8,6 +8,8 @@ pub struct View<'a, T> {
     pub(crate) dense: &'a [EntityId],
     pub(crate) data: &'a [T],
     pub(crate) pack_info: &'a PackInfo<T>,
+    pub(crate) ticks: Option<&'a Ticks>,
+    pub(crate) system_ticks: SystemTicks,
 }
 
 impl<'a, T> Clone for View<'a, T> {
@@ -17,6 +19,8 @@ impl<'a, T> Clone for View<'a, T> {
             dense: self.dense,
             data: self.data,
             pack_info: self.pack_info,
+            ticks: self.ticks,
+            system_ticks: self.system_ticks,
         }
     }
 }
@@ -57,12 +61,16 @@ impl<T> View<'_, T> {
                 dense: &self.dense[pack.inserted..pack.inserted + pack.modified],
                 data: &self.data[pack.inserted..pack.inserted + pack.modified],
                 pack_info: self.pack_info,
+                ticks: self.ticks,
+                system_ticks: self.system_ticks,
             },
             _ => View {
                 sparse: &[],
                 dense: &[],
                 data: &[],
                 pack_info: self.pack_info,
+                ticks: self.ticks,
+                system_ticks: self.system_ticks,
             },
         }
     }
@@ -73,12 +81,16 @@ impl<T> View<'_, T> {
                 dense: &self.dense[0..pack.inserted],
                 data: &self.data[0..pack.inserted],
                 pack_info: self.pack_info,
+                ticks: self.ticks,
+                system_ticks: self.system_ticks,
             },
             _ => View {
                 sparse: &[],
                 dense: &[],
                 data: &[],
                 pack_info: self.pack_info,
+                ticks: self.ticks,
+                system_ticks: self.system_ticks,
             },
         }
     }
@@ -86,6 +98,56 @@ impl<T> View<'_, T> {
     pub(crate) fn is_unique(&self) -> bool {
         self.sparse.is_empty() && self.dense.is_empty() && self.data.len() == 1
     }
+    /// Returns true if `entity`'s component was added since the system last ran.\
+    /// Always returns false if the storage doesn't track changes.
+    pub fn is_added(&self, entity: EntityId) -> bool {
+        match self.ticks {
+            Some(ticks) if self.contains(entity) => {
+                self.system_ticks.is_newer(ticks.inserted[entity.index()])
+            }
+            _ => false,
+        }
+    }
+    /// Returns true if `entity`'s component was added or modified since the system last ran.\
+    /// Always returns false if the storage doesn't track changes.
+    pub fn is_changed(&self, entity: EntityId) -> bool {
+        match self.ticks {
+            Some(ticks) if self.contains(entity) => {
+                self.system_ticks.is_newer(ticks.modified[entity.index()])
+            }
+            _ => false,
+        }
+    }
+    /// Returns an iterator over the entities whose component was added since the system last ran.\
+    /// The iterator is empty if the storage doesn't track changes.\
+    /// Use `Added` to filter an iteration over multiple storages.
+    pub fn added(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.dense
+            .iter()
+            .copied()
+            .filter(move |&entity| self.is_added(entity))
+    }
+    /// Returns an iterator over the entities whose component was added or modified since the system last ran.\
+    /// The iterator is empty if the storage doesn't track changes.\
+    /// Use `Changed` to filter an iteration over multiple storages.
+    pub fn changed(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.dense
+            .iter()
+            .copied()
+            .filter(move |&entity| self.is_changed(entity))
+    }
+    /// Returns an iterator over the entities whose component was removed since the system last ran.\
+    /// The iterator is empty if the storage doesn't track changes.
+    ///
+    /// The entities might have a component again if it was added back later.
+    pub fn removed(&self) -> impl Iterator<Item = EntityId> + '_ {
+        let system_ticks = self.system_ticks;
+        self.ticks
+            .into_iter()
+            .flat_map(|ticks| ticks.removed.iter())
+            .filter(move |&&(_, tick)| system_ticks.is_newer(tick))
+            .map(|&(entity, _)| entity)
+    }
 }
 
 /// Mutable view into a `Storage`.
@@ -94,6 +156,9 @@ pub struct ViewMut<'a, T> {
     pub(crate) dense: &'a mut Vec<EntityId>,
     pub(crate) data: &'a mut Vec<T>,
     pub(crate) pack_info: &'a mut PackInfo<T>,
+    pub(crate) ticks: &'a mut Option<Ticks>,
+    pub(crate) hooks: &'a Hooks<T>,
+    pub(crate) system_ticks: SystemTicks,
 }
 
 impl<'a, T: 'static> ViewMut<'a, T> {
@@ -102,16 +167,77 @@ impl<'a, T: 'static> ViewMut<'a, T> {
         if entity.index() >= self.sparse.len() {
             self.sparse.resize(entity.index() + 1, 0);
         }
+        let hooks = self.hooks;
         if let Some(data) = self.get_mut(entity) {
+            hooks.removed(entity, data);
             std::mem::swap(data, &mut value);
+            hooks.inserted(entity, data);
             Some(value)
         } else {
             unsafe { *self.sparse.get_unchecked_mut(entity.index()) = self.dense.len() };
             self.dense.push(entity);
             self.data.push(value);
+            if let Some(ticks) = self.ticks {
+                ticks.insert(entity.index(), self.system_ticks.current);
+            }
+            hooks.inserted(entity, self.data.last_mut().unwrap());
             None
         }
     }
+    /// Reserves capacity for at least `additional` more components.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.dense.reserve(additional);
+        self.data.reserve(additional);
+    }
+    /// Adds the components of new entities pushed at the end of `dense` and `data` from `start`.
+    ///
+    /// `sparse_len` is greater than the index of all new entities.\
+    /// When `pack` is true the components are moved all at once at the end of their pack,
+    /// or at the end of the inserted window for update packs.
+    pub(crate) fn end_bulk_insert(&mut self, start: usize, sparse_len: usize, pack: bool) {
+        let added = self.dense.len() - start;
+        let mut first = start;
+        if pack {
+            match &mut self.pack_info.pack {
+                Pack::Tight(pack) => {
+                    first = pack.len;
+                    pack.len += added;
+                }
+                Pack::Loose(pack) => {
+                    first = pack.len;
+                    pack.len += added;
+                }
+                Pack::Update(pack) => {
+                    first = pack.inserted;
+                    pack.inserted += added;
+                }
+                Pack::NoPack => {}
+            }
+            // components after the pack keep their order, shifted by `added`
+            self.dense[first..].rotate_right(added);
+            self.data[first..].rotate_right(added);
+        }
+
+        if sparse_len > self.sparse.len() {
+            self.sparse.resize(sparse_len, 0);
+        }
+        for (index, entity) in self.dense.iter().enumerate().skip(first) {
+            unsafe { *self.sparse.get_unchecked_mut(entity.index()) = index };
+        }
+
+        let new = first..first + added;
+        if let Some(ticks) = self.ticks {
+            ticks.insert_many(
+                &self.dense[new.clone()],
+                sparse_len,
+                self.system_ticks.current,
+            );
+        }
+        let hooks = self.hooks;
+        for (&entity, component) in self.dense[new.clone()].iter().zip(&mut self.data[new]) {
+            hooks.inserted(entity, component);
+        }
+    }
     pub(crate) fn contains(&self, entity: EntityId) -> bool {
         self.as_non_mut().contains(entity)
     }
@@ -129,6 +255,9 @@ impl<'a, T: 'static> ViewMut<'a, T> {
     /// Returns a mutable reference to the component if the `entity` has it.
     pub(crate) fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
         if self.contains(entity) {
+            if let Some(ticks) = self.ticks {
+                ticks.modified[entity.index()] = self.system_ticks.current;
+            }
             Some(unsafe {
                 self.data
                     .get_unchecked_mut(*self.sparse.get_unchecked(entity.index()))
@@ -139,7 +268,22 @@ impl<'a, T: 'static> ViewMut<'a, T> {
     }
     /// Remove the component if the `entity` has it and returns it.
     pub(crate) fn remove(&mut self, entity: EntityId) -> Option<T> {
+        let mut component = self.take(entity)?;
+        self.hooks.removed(entity, &mut component);
+        Some(component)
+    }
+    /// Remove the component if the `entity` has it and hands it to the `on_delete` hook.
+    pub(crate) fn delete(&mut self, entity: EntityId) {
+        if let Some(component) = self.take(entity) {
+            self.hooks.deleted(entity, component);
+        }
+    }
+    /// Remove the component if the `entity` has it without running any hook.
+    fn take(&mut self, entity: EntityId) -> Option<T> {
         if self.contains(entity) {
+            if let Some(ticks) = self.ticks {
+                ticks.remove(entity, self.system_ticks);
+            }
             let mut dense_index = unsafe { *self.sparse.get_unchecked(entity.index()) };
             match &mut self.pack_info.pack {
                 Pack::Tight(pack_info) => {
@@ -212,6 +356,26 @@ impl<'a, T: 'static> ViewMut<'a, T> {
             None
         }
     }
+    /// Removes all components, packs are kept but emptied.
+    pub(crate) fn clear(&mut self) {
+        if let Some(ticks) = self.ticks {
+            ticks.remove_many(self.dense.iter().copied(), self.system_ticks);
+        }
+        match &mut self.pack_info.pack {
+            Pack::Tight(pack) => pack.len = 0,
+            Pack::Loose(pack) => pack.len = 0,
+            Pack::Update(pack) => {
+                pack.inserted = 0;
+                pack.modified = 0;
+            }
+            Pack::NoPack => {}
+        }
+        self.sparse.clear();
+        let hooks = self.hooks;
+        for (entity, component) in self.dense.drain(..).zip(self.data.drain(..)) {
+            hooks.deleted(entity, component);
+        }
+    }
     /// Returns the number of components in the view.
     pub fn len(&self) -> usize {
         self.data.len()
@@ -228,6 +392,11 @@ impl<'a, T: 'static> ViewMut<'a, T> {
             sparse_len: self.sparse.len(),
             len: self.dense.len(),
             pack_info: self.pack_info,
+            modified: self
+                .ticks
+                .as_mut()
+                .map_or(std::ptr::null_mut(), |ticks| ticks.modified.as_mut_ptr()),
+            current: self.system_ticks.current,
             _phantom: PhantomData,
         }
     }
@@ -240,6 +409,11 @@ impl<'a, T: 'static> ViewMut<'a, T> {
             sparse_len: self.sparse.len(),
             len: self.dense.len(),
             pack_info: self.pack_info,
+            modified: self
+                .ticks
+                .as_mut()
+                .map_or(std::ptr::null_mut(), |ticks| ticks.modified.as_mut_ptr()),
+            current: self.system_ticks.current,
             _phantom: PhantomData,
         }
     }
@@ -250,6 +424,8 @@ impl<'a, T: 'static> ViewMut<'a, T> {
             dense: self.dense,
             data: self.data,
             pack_info: self.pack_info,
+            ticks: self.ticks.as_ref(),
+            system_ticks: self.system_ticks,
         }
     }
     pub(crate) fn pack(&mut self, entity: EntityId) {
@@ -321,12 +497,16 @@ impl<'a, T: 'static> ViewMut<'a, T> {
                 dense: &self.dense[pack.inserted..pack.inserted + pack.modified],
                 data: &self.data[pack.inserted..pack.inserted + pack.modified],
                 pack_info: self.pack_info,
+                ticks: self.ticks.as_ref(),
+                system_ticks: self.system_ticks,
             },
             _ => View {
                 sparse: &[],
                 dense: &[],
                 data: &[],
                 pack_info: self.pack_info,
+                ticks: self.ticks.as_ref(),
+                system_ticks: self.system_ticks,
             },
         }
     }
@@ -361,12 +541,16 @@ impl<'a, T: 'static> ViewMut<'a, T> {
                 dense: &self.dense[0..pack.inserted],
                 data: &self.data[0..pack.inserted],
                 pack_info: self.pack_info,
+                ticks: self.ticks.as_ref(),
+                system_ticks: self.system_ticks,
             },
             _ => View {
                 sparse: &[],
                 dense: &[],
                 data: &[],
                 pack_info: self.pack_info,
+                ticks: self.ticks.as_ref(),
+                system_ticks: self.system_ticks,
             },
         }
     }
@@ -434,6 +618,55 @@ impl<'a, T: 'static> ViewMut<'a, T> {
     pub(crate) fn is_unique(&self) -> bool {
         self.as_non_mut().is_unique()
     }
+    /// Returns true if `entity`'s component was added since the system last ran.\
+    /// Always returns false if the storage doesn't track changes.
+    pub fn is_added(&self, entity: EntityId) -> bool {
+        self.as_non_mut().is_added(entity)
+    }
+    /// Returns true if `entity`'s component was added or modified since the system last ran.\
+    /// Always returns false if the storage doesn't track changes.
+    pub fn is_changed(&self, entity: EntityId) -> bool {
+        self.as_non_mut().is_changed(entity)
+    }
+    /// Returns an iterator over the entities whose component was added since the system last ran.\
+    /// The iterator is empty if the storage doesn't track changes.
+    pub fn added(&self) -> impl Iterator<Item = EntityId> + '_ {
+        let view = self.as_non_mut();
+        view.dense
+            .iter()
+            .copied()
+            .filter(move |&entity| view.is_added(entity))
+    }
+    /// Returns an iterator over the entities whose component was added or modified since the system last ran.\
+    /// The iterator is empty if the storage doesn't track changes.
+    pub fn changed(&self) -> impl Iterator<Item = EntityId> + '_ {
+        let view = self.as_non_mut();
+        view.dense
+            .iter()
+            .copied()
+            .filter(move |&entity| view.is_changed(entity))
+    }
+    /// Returns an iterator over the entities whose component was removed since the system last ran.\
+    /// The iterator is empty if the storage doesn't track changes.
+    ///
+    /// The entities might have a component again if it was added back later.
+    pub fn removed(&self) -> impl Iterator<Item = EntityId> + '_ {
+        let system_ticks = self.system_ticks;
+        self.ticks
+            .iter()
+            .flat_map(|ticks| ticks.removed.iter())
+            .filter(move |&&(_, tick)| system_ticks.is_newer(tick))
+            .map(|&(entity, _)| entity)
+    }
+    /// Forgets all removals, for all systems.
+    ///
+    /// Removals are forgotten once all workload systems saw them.
+    /// Without workloads or when a workload system never runs, they're kept until this method is called.
+    pub fn clear_removed(&mut self) {
+        if let Some(ticks) = self.ticks.as_mut() {
+            ticks.removed.clear();
+        }
+    }
 }
 
 // Used in iterators
@@ -444,6 +677,9 @@ pub struct RawViewMut<'a, T> {
     pub(crate) len: usize,
     pub(crate) data: *mut T,
     pub(crate) pack_info: *mut PackInfo<T>,
+    // modification tick of each entity, null if the storage doesn't track changes
+    pub(crate) modified: *mut u32,
+    pub(crate) current: u32,
     _phantom: PhantomData<&'a ()>,
 }
 
@@ -455,6 +691,12 @@ impl<'a, T> RawViewMut<'a, T> {
             && *self.sparse.add(entity.index()) < self.len
             && *self.dense.add(*self.sparse.add(entity.index())) == entity
     }
+    /// Marks the component at `index` in dense as modified at the current tick.
+    pub(crate) unsafe fn mark_tick(&self, index: usize) {
+        if !self.modified.is_null() {
+            *self.modified.add((*self.dense.add(index)).index()) = self.current;
+        }
+    }
 }
 
 impl<'a, T> Clone for RawViewMut<'a, T> {
@@ -466,6 +708,8 @@ impl<'a, T> Clone for RawViewMut<'a, T> {
             sparse_len: self.sparse_len,
             len: self.len,
             pack_info: self.pack_info,
+            modified: self.modified,
+            current: self.current,
             _phantom: PhantomData,
         }
     }
@@ -1,5 +1,5 @@
 use crate::sparse_set::{Pack, ViewMut};
-use crate::storage::EntityId;
+use crate::storage::{EntitiesViewMut, EntityId};
 use std::any::TypeId;
 
 pub trait ViewAddEntity {
@@ -133,3 +133,116 @@ macro_rules! view_add_entity {
 }
 
 view_add_entity![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+
+pub trait ViewBulkAddEntity<C> {
+    fn bulk_add_entity(self, components: C, entities: &mut EntitiesViewMut<'_>) -> Vec<EntityId>;
+}
+
+impl<T: 'static + Send + Sync, I: IntoIterator<Item = T>> ViewBulkAddEntity<I> for ViewMut<'_, T> {
+    fn bulk_add_entity(
+        mut self,
+        components: I,
+        entities: &mut EntitiesViewMut<'_>,
+    ) -> Vec<EntityId> {
+        (&mut self).bulk_add_entity(components, entities)
+    }
+}
+
+impl<T: 'static + Send + Sync, I: IntoIterator<Item = T>> ViewBulkAddEntity<I>
+    for &mut ViewMut<'_, T>
+{
+    fn bulk_add_entity(self, components: I, entities: &mut EntitiesViewMut<'_>) -> Vec<EntityId> {
+        (self,).bulk_add_entity((components,), entities)
+    }
+}
+
+macro_rules! impl_view_bulk_add_entity {
+    ($(($type: ident, $iter: ident, $index: tt))+) => {
+        impl<$($type: 'static + Send + Sync, $iter: IntoIterator<Item = $type>),+> ViewBulkAddEntity<($($iter,)+)> for ($(ViewMut<'_, $type>,)+) {
+            fn bulk_add_entity(mut self, components: ($($iter,)+), entities: &mut EntitiesViewMut<'_>) -> Vec<EntityId> {
+                ($(&mut self.$index,)+).bulk_add_entity(components, entities)
+            }
+        }
+        impl<$($type: 'static + Send + Sync, $iter: IntoIterator<Item = $type>),+> ViewBulkAddEntity<($($iter,)+)> for ($(&mut ViewMut<'_, $type>,)+) {
+            fn bulk_add_entity(self, components: ($($iter,)+), entities: &mut EntitiesViewMut<'_>) -> Vec<EntityId> {
+                let mut iters = ($(components.$index.into_iter(),)+);
+
+                // the shortest column decides how many entities are added
+                let additional = [$({
+                    let (lower, upper) = iters.$index.size_hint();
+                    upper.unwrap_or(lower)
+                }),+].iter().copied().min().unwrap_or(0);
+                entities.reserve(additional);
+                $(
+                    self.$index.reserve(additional);
+                )+
+
+                // all components are pushed at the end of the storages then moved once
+                let starts = [$(self.$index.dense.len()),+];
+                let mut new_entities = Vec::with_capacity(additional);
+                loop {
+                    let component = ($(
+                        match iters.$index.next() {
+                            Some(component) => component,
+                            None => break,
+                        },
+                    )+);
+
+                    let entity = entities.generate();
+                    $(
+                        self.$index.dense.push(entity);
+                        self.$index.data.push(component.$index);
+                    )+
+                    new_entities.push(entity);
+                }
+
+                let sparse_len = new_entities.iter().map(|entity| entity.index() + 1).max().unwrap_or(0);
+
+                let mut type_ids = [$(TypeId::of::<$type>()),+];
+                type_ids.sort_unstable();
+
+                // same rules as add_entity, they don't depend on the entity
+                let mut should_pack = Vec::with_capacity(type_ids.len());
+                $(
+                    let type_id = TypeId::of::<$type>();
+
+                    let pack = if should_pack.contains(&type_id) {
+                        true
+                    } else {
+                        match &self.$index.pack_info.pack {
+                            Pack::Tight(pack) => if let Ok(types) = pack.check_types(&type_ids) {
+                                should_pack.extend_from_slice(&pack.types);
+                                !types.is_empty()
+                            } else {
+                                false
+                            }
+                            Pack::Loose(pack) => if let Ok(types) = pack.check_all_types(&type_ids) {
+                                should_pack.extend_from_slice(&pack.tight_types);
+                                !types.is_empty()
+                            } else {
+                                false
+                            }
+                            Pack::Update(_) => true,
+                            Pack::NoPack => false,
+                        }
+                    };
+                    self.$index.end_bulk_insert(starts[$index], sparse_len, pack);
+                )+
+
+                new_entities
+            }
+        }
+    }
+}
+
+macro_rules! view_bulk_add_entity {
+    ($(($type: ident, $iter: ident, $index: tt))*;($type1: ident, $iter1: ident, $index1: tt) $(($queue_type: ident, $queue_iter: ident, $queue_index: tt))*) => {
+        impl_view_bulk_add_entity![$(($type, $iter, $index))*];
+        view_bulk_add_entity![$(($type, $iter, $index))* ($type1, $iter1, $index1); $(($queue_type, $queue_iter, $queue_index))*];
+    };
+    ($(($type: ident, $iter: ident, $index: tt))*;) => {
+        impl_view_bulk_add_entity![$(($type, $iter, $index))*];
+    }
+}
+
+view_bulk_add_entity![(A, IA, 0); (B, IB, 1) (C, IC, 2) (D, ID, 3) (E, IE, 4) (F, IF, 5) (G, IG, 6) (H, IH, 7) (I, II, 8) (J, IJ, 9)];
@@ -1,9 +1,9 @@
 mod hasher;
 mod view;
 
-use super::{Entities, EntityId, Storage};
-use crate::atomic_refcell::AtomicRefCell;
-use crate::unknown_storage::UnknownStorage;
+use super::{DynamicStorages, Entities, EntityId, Storage};
+use crate::commands::CommandQueue;
+use crate::sparse_set::SystemTicks;
 pub(crate) use hasher::TypeIdHasher;
 use std::any::TypeId;
 use std::collections::HashMap;
@@ -14,25 +14,30 @@ pub use view::AllStoragesViewMut;
 // Wrapper to hide `TypeIdHasher` and the whole `HashMap` from public interface
 pub struct AllStorages(pub(crate) HashMap<TypeId, Storage, BuildHasherDefault<TypeIdHasher>>);
 
+/// A storage, its number of components and how many bytes it uses.
+#[derive(Clone, Debug)]
+pub struct StorageInfo {
+    pub type_name: &'static str,
+    pub len: usize,
+    pub memory_usage: usize,
+}
+
 impl Default for AllStorages {
     fn default() -> Self {
         let mut storages = HashMap::default();
 
-        let entities = Entities::default();
-        let unknown: [*const (); 2] = unsafe {
-            *(&(&entities as &dyn UnknownStorage as *const _) as *const *const _
-                as *const [*const (); 2])
-        };
-
         storages.insert(
             TypeId::of::<Entities>(),
-            Storage {
-                container: AtomicRefCell::new(Box::new(entities)),
-                unknown: unknown[1],
-            },
+            Storage::from_container(Entities::default()),
+        );
+        storages.insert(
+            TypeId::of::<DynamicStorages>(),
+            Storage::from_container(DynamicStorages::default()),
         );
 
-        AllStorages(storages)
+        let mut all_storages = AllStorages(storages);
+        all_storages.register_unique(CommandQueue::default());
+        all_storages
     }
 }
 
@@ -54,7 +59,7 @@ impl AllStorages {
             *self.0.get_mut(&type_id).unwrap() = storage;
         }
     }
-    pub(crate) fn view_mut(&mut self) -> AllStoragesViewMut {
-        AllStoragesViewMut(&mut self.0)
+    pub(crate) fn view_mut(&mut self, ticks: SystemTicks) -> AllStoragesViewMut {
+        AllStoragesViewMut(&mut self.0, ticks)
     }
 }
@@ -1,4 +1,6 @@
-use super::{Entities, EntityId, Storage, TypeIdHasher};
+use super::{Entities, EntityId, Storage, StorageInfo, TypeIdHasher};
+use crate::hierarchy::{self, Child, HierarchyIter, Parent};
+use crate::sparse_set::SystemTicks;
 use std::any::TypeId;
 use std::collections::HashMap;
 use std::hash::BuildHasherDefault;
@@ -7,6 +9,7 @@ use std::hash::BuildHasherDefault;
 /// Let you remove entities.
 pub struct AllStoragesViewMut<'a>(
     pub(super) &'a mut HashMap<TypeId, Storage, BuildHasherDefault<TypeIdHasher>>,
+    pub(super) SystemTicks,
 );
 
 impl AllStoragesViewMut<'_> {
@@ -41,25 +44,16 @@ impl AllStoragesViewMut<'_> {
         if entities.delete(entity) {
             drop(entities);
 
+            self.detach_hierarchy(entity);
+
             let mut storage_to_unpack = Vec::new();
 
             for storage in self.0.values_mut() {
-                let observers = storage.delete(entity).unwrap();
-                storage_to_unpack.reserve(observers.len());
-
-                let mut i = 0;
-                for observer in observers.iter().copied() {
-                    while i < storage_to_unpack.len() && observer < storage_to_unpack[i] {
-                        i += 1;
-                    }
-                    if storage_to_unpack.is_empty() || observer != storage_to_unpack[i] {
-                        storage_to_unpack.insert(i, observer);
-                    }
-                }
+                merge_observers(&mut storage_to_unpack, storage.delete(entity, self.1));
             }
 
             for storage in storage_to_unpack {
-                self.0.get_mut(&storage).unwrap().unpack(entity).unwrap();
+                self.0.get_mut(&storage).unwrap().unpack(entity);
             }
 
             true
@@ -67,4 +61,207 @@ impl AllStoragesViewMut<'_> {
             false
         }
     }
+    /// Delete multiple entities and all their components.
+    /// Returns the number of entities that were alive.
+    ///
+    /// Each storage is only visited once.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize, u32)>();
+    ///
+    /// let entities = world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+    ///     |(mut entities, mut usizes, mut u32s)| {
+    ///         entities.bulk_add_entity((&mut usizes, &mut u32s), (0..3, 0..3))
+    ///     },
+    /// );
+    ///
+    /// world.run::<AllStorages, _, _>(|mut all_storages| {
+    ///     assert_eq!(all_storages.delete_many(&entities[..2]), 2);
+    /// });
+    ///
+    /// world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+    ///     assert_eq!(usizes.len(), 1);
+    ///     assert_eq!((&usizes, &u32s).get(entities[2]), Some((&2, &2)));
+    /// });
+    /// ```
+    pub fn delete_many(&mut self, entities: &[EntityId]) -> usize {
+        let mut entities_storage = self.0[&TypeId::of::<Entities>()].entities_mut().unwrap();
+        let alive: Vec<_> = entities
+            .iter()
+            .copied()
+            .filter(|&entity| entities_storage.delete(entity))
+            .collect();
+        drop(entities_storage);
+
+        if alive.is_empty() {
+            return 0;
+        }
+
+        for &entity in &alive {
+            self.detach_hierarchy(entity);
+        }
+
+        let mut storage_to_unpack = Vec::new();
+
+        for storage in self.0.values_mut() {
+            merge_observers(&mut storage_to_unpack, storage.delete_many(&alive, self.1));
+        }
+
+        for storage in storage_to_unpack {
+            let storage = self.0.get_mut(&storage).unwrap();
+            for &entity in &alive {
+                storage.unpack(entity);
+            }
+        }
+
+        alive.len()
+    }
+    /// Delete all entities and their components.
+    ///
+    /// Packs are kept, unique components are left untouched.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize, u32)>();
+    /// world.tight_pack::<(usize, u32)>();
+    ///
+    /// let entities = world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+    ///     |(mut entities, mut usizes, mut u32s)| {
+    ///         entities.bulk_add_entity((&mut usizes, &mut u32s), (0..3, 0..3))
+    ///     },
+    /// );
+    ///
+    /// world.run::<AllStorages, _, _>(|mut all_storages| {
+    ///     all_storages.clear();
+    ///     assert!(!all_storages.delete(entities[0]));
+    /// });
+    ///
+    /// world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+    ///     assert!(usizes.is_empty());
+    ///     assert!(u32s.is_empty());
+    /// });
+    /// ```
+    pub fn clear(&mut self) {
+        for storage in self.0.values_mut() {
+            storage.clear(self.1);
+        }
+    }
+    /// Returns the type, number of components and memory used of each storage, sorted by type name.
+    ///
+    /// `Entities` counts living entities and `DynamicStorages` the components of all dynamic storages.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::default();
+    /// world.entity_builder().with(0u32).build();
+    /// world.entity_builder().with(1u32).build();
+    ///
+    /// world.run::<AllStorages, _, _>(|mut all_storages| {
+    ///     let infos = all_storages.storage_infos();
+    ///     let u32s = infos.iter().find(|info| info.type_name == "u32").unwrap();
+    ///     assert_eq!(u32s.len, 2);
+    ///     assert!(u32s.memory_usage >= 2 * std::mem::size_of::<u32>());
+    /// });
+    /// ```
+    pub fn storage_infos(&mut self) -> Vec<StorageInfo> {
+        let mut infos: Vec<_> = self
+            .0
+            .values_mut()
+            .map(|storage| StorageInfo {
+                type_name: storage.type_name(),
+                len: storage.len(),
+                memory_usage: storage.memory_usage(),
+            })
+            .collect();
+        infos.sort_unstable_by_key(|info| info.type_name);
+        infos
+    }
+    /// Delete an entity, all its descendants and their components.
+    /// Returns `true` if `entity` was alive.
+    ///
+    /// Descendants are found using [Parent] and [Child] components.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(Parent, Child)>();
+    ///
+    /// let (root, child, grand_child) = world.run::<(EntitiesMut, &mut Parent, &mut Child), _, _>(
+    ///     |(mut entities, mut parents, mut children)| {
+    ///         let root = entities.add_entity((), ());
+    ///         let child = entities.add_entity((), ());
+    ///         let grand_child = entities.add_entity((), ());
+    ///         (&entities, &mut parents, &mut children).attach(child, root);
+    ///         (&entities, &mut parents, &mut children).attach(grand_child, child);
+    ///         (root, child, grand_child)
+    ///     },
+    /// );
+    ///
+    /// world.run::<AllStorages, _, _>(|mut all_storages| {
+    ///     assert!(all_storages.delete_recursive(root));
+    /// });
+    ///
+    /// world.run::<(&Parent, &Child), _, _>(|(parents, children)| {
+    ///     assert!(parents.is_empty());
+    ///     assert!(children.is_empty());
+    /// });
+    /// ```
+    /// [Parent]: struct.Parent.html
+    /// [Child]: struct.Child.html
+    pub fn delete_recursive(&mut self, entity: EntityId) -> bool {
+        let mut descendants = Vec::new();
+
+        if let (Some(parents), Some(children)) = (
+            self.0.get(&TypeId::of::<Parent>()),
+            self.0.get(&TypeId::of::<Child>()),
+        ) {
+            let parents = parents.sparse_set::<Parent>().unwrap();
+            let children = children.sparse_set::<Child>().unwrap();
+            descendants.extend((&parents.view(), &children.view()).descendants(entity));
+        }
+
+        if self.delete(entity) {
+            for descendant in descendants {
+                self.delete(descendant);
+            }
+            true
+        } else {
+            false
+        }
+    }
+    /// Removes `entity` from its parent's children, its own children become roots.
+    fn detach_hierarchy(&mut self, entity: EntityId) {
+        if let (Some(parents), Some(children)) = (
+            self.0.get(&TypeId::of::<Parent>()),
+            self.0.get(&TypeId::of::<Child>()),
+        ) {
+            let mut parents = parents.sparse_set_mut::<Parent>().unwrap();
+            let mut children = children.sparse_set_mut::<Child>().unwrap();
+            let mut parents = parents.view_mut_with_ticks(self.1);
+            let mut children = children.view_mut_with_ticks(self.1);
+
+            let orphans: Vec<_> = (&parents, &children).children(entity).collect();
+            for orphan in orphans {
+                children.remove(orphan);
+            }
+            parents.remove(entity);
+            hierarchy::detach(&mut parents, &mut children, entity);
+        }
+    }
+}
+
+/// Adds `observers` to `storage_to_unpack`, both are sorted.\
+/// Storages already present aren't added twice.
+fn merge_observers(storage_to_unpack: &mut Vec<TypeId>, observers: &[TypeId]) {
+    storage_to_unpack.reserve(observers.len());
+
+    let mut i = 0;
+    for &observer in observers {
+        while i < storage_to_unpack.len() && storage_to_unpack[i] < observer {
+            i += 1;
+        }
+        if i == storage_to_unpack.len() || observer != storage_to_unpack[i] {
+            storage_to_unpack.insert(i, observer);
+        }
+    }
 }
@@ -0,0 +1,65 @@
+use super::DropFn;
+use std::alloc::Layout;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+pub(super) const MAX_ALIGN: usize = 16;
+
+// Backing memory of the components, it makes their bytes aligned to `MAX_ALIGN`.
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+pub(super) struct Chunk([u8; MAX_ALIGN]);
+
+/// Component registered at runtime, stored in a regular `SparseSet`.
+///
+/// Dereferences to the component's bytes.\
+/// The component's drop function runs when it's dropped.
+pub struct DynamicData {
+    pub(super) chunks: Box<[Chunk]>,
+    size: usize,
+    drop: Option<Arc<DropFn>>,
+}
+
+impl DynamicData {
+    /// Copies `bytes` into aligned memory.
+    pub(super) fn new(bytes: &[u8], drop: Option<Arc<DropFn>>) -> Self {
+        let padded = Layout::from_size_align(bytes.len(), MAX_ALIGN)
+            .unwrap()
+            .pad_to_align();
+        let mut data = DynamicData {
+            chunks: vec![Chunk([0; MAX_ALIGN]); padded.size() / MAX_ALIGN].into_boxed_slice(),
+            size: bytes.len(),
+            drop,
+        };
+        data.copy_from_slice(bytes);
+        data
+    }
+    /// Returns the component's bytes without running its drop function.\
+    /// The caller becomes responsible for the component.
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        self.drop = None;
+        self.to_vec()
+    }
+}
+
+impl Deref for DynamicData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.chunks.as_ptr() as *const u8, self.size) }
+    }
+}
+
+impl DerefMut for DynamicData {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.chunks.as_mut_ptr() as *mut u8, self.size) }
+    }
+}
+
+impl Drop for DynamicData {
+    fn drop(&mut self) {
+        if let Some(drop) = self.drop.take() {
+            drop(self);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+mod data;
+mod view;
+
+use super::EntityId;
+use crate::atomic_refcell::AtomicRefCell;
+use crate::sparse_set::{SparseSet, SystemTicks};
+use crate::unknown_storage::UnknownStorage;
+use data::Chunk;
+pub use data::DynamicData;
+use std::alloc::Layout;
+use std::any::{type_name, Any, TypeId};
+use std::mem::size_of;
+use std::sync::Arc;
+pub use view::{DynamicStoragesView, DynamicView, DynamicViewMut};
+
+/// Type used to borrow `DynamicStorages` while letting other systems using dynamic storages run in parallel.
+///
+/// **Systems of the same batch can then borrow the same dynamic storage and panic.**\
+/// The scheduler doesn't know which dynamic storages a system accesses,
+/// only use it when systems of the same workload access different storages or only use `view`.
+/// `DynamicStorages` never runs in parallel with other systems using dynamic storages.
+pub struct SharedDynamicStorages;
+
+/// Identifies a component type registered at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DynamicId(usize);
+
+type DropFn = dyn Fn(&mut [u8]) + Send + Sync;
+
+/// Describes a component type only known at runtime.
+///
+/// Components are handled as bytes, `layout` gives their size and alignment.\
+/// The drop function receives the bytes of each component the storage destroys.
+pub struct DynamicComponent {
+    name: String,
+    layout: Layout,
+    drop: Option<Arc<DropFn>>,
+}
+
+impl DynamicComponent {
+    /// Describes a component named `name` made of `layout.size()` bytes.
+    ///
+    /// # Panics
+    /// `layout`'s alignment can't be greater than 16.
+    pub fn new(name: impl Into<String>, layout: Layout) -> Self {
+        assert!(
+            layout.align() <= data::MAX_ALIGN,
+            "Dynamic components can't be aligned to more than {} bytes.",
+            data::MAX_ALIGN
+        );
+
+        DynamicComponent {
+            name: name.into(),
+            layout,
+            drop: None,
+        }
+    }
+    /// Sets the function run when the storage destroys a component.
+    pub fn with_drop<F: Fn(&mut [u8]) + Send + Sync + 'static>(mut self, drop: F) -> Self {
+        self.drop = Some(Arc::new(drop));
+        self
+    }
+}
+
+/// Storages of all components registered at runtime.
+///
+/// Unlike `AllStorages`, it's keyed by `DynamicId` and each storage is borrowed individually.\
+/// Each component type is stored in a regular `SparseSet` of `DynamicData`,
+/// `DynamicView::view` and `DynamicViewMut::view_mut` return views that can be iterated with other storages.\
+/// Dynamic storages can't be packed, don't track changes and don't have hooks.
+///
+/// Borrowing it prevents other systems borrowing it from running in parallel,
+/// see `SharedDynamicStorages` to lift this restriction.
+// `components` is kept outside the storages to look them up without borrowing them.
+#[derive(Default)]
+pub struct DynamicStorages {
+    components: Vec<DynamicComponent>,
+    sparse_sets: Vec<AtomicRefCell<SparseSet<DynamicData>>>,
+}
+
+impl DynamicStorages {
+    pub(crate) fn register(&mut self, component: DynamicComponent) -> DynamicId {
+        self.components.push(component);
+        self.sparse_sets
+            .push(AtomicRefCell::new(SparseSet::default()));
+        DynamicId(self.sparse_sets.len() - 1)
+    }
+    pub(crate) fn view(&self) -> DynamicStoragesView<'_> {
+        DynamicStoragesView(self)
+    }
+}
+
+impl UnknownStorage for DynamicStorages {
+    fn delete(&mut self, entity: EntityId, ticks: SystemTicks) -> &[TypeId] {
+        for sparse_set in &mut self.sparse_sets {
+            UnknownStorage::delete(sparse_set.get_mut(), entity, ticks);
+        }
+        &[]
+    }
+    fn delete_many(&mut self, entities: &[EntityId], ticks: SystemTicks) -> &[TypeId] {
+        for sparse_set in &mut self.sparse_sets {
+            UnknownStorage::delete_many(sparse_set.get_mut(), entities, ticks);
+        }
+        &[]
+    }
+    fn clear(&mut self, ticks: SystemTicks) {
+        for sparse_set in &mut self.sparse_sets {
+            UnknownStorage::clear(sparse_set.get_mut(), ticks);
+        }
+    }
+    fn unpack(&mut self, _entity: EntityId) {}
+    // the dynamic storages can't be borrowed while their container is used as an `UnknownStorage`
+    fn len(&self) -> usize {
+        self.sparse_sets
+            .iter()
+            .map(|sparse_set| sparse_set.try_borrow().unwrap().len())
+            .sum()
+    }
+    fn memory_usage(&self) -> usize {
+        size_of::<Self>()
+            + self
+                .components
+                .iter()
+                .map(|component| component.name.capacity())
+                .sum::<usize>()
+            + self.components.capacity() * size_of::<DynamicComponent>()
+            + self.sparse_sets.capacity() * size_of::<AtomicRefCell<SparseSet<DynamicData>>>()
+            + self
+                .sparse_sets
+                .iter()
+                .map(|sparse_set| {
+                    let sparse_set = sparse_set.try_borrow().unwrap();
+                    // the sparse set's size is already counted in the `Vec`'s capacity
+                    sparse_set.memory_usage() - size_of::<SparseSet<DynamicData>>()
+                        + sparse_set
+                            .view()
+                            .data
+                            .iter()
+                            .map(|component| component.chunks.len() * size_of::<Chunk>())
+                            .sum::<usize>()
+                })
+                .sum::<usize>()
+    }
+    fn type_name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+    fn any(&self) -> &dyn Any {
+        self
+    }
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,212 @@
+use super::{DynamicComponent, DynamicData, DynamicId, DynamicStorages};
+use crate::atomic_refcell::{Ref, RefMut};
+use crate::error;
+use crate::sparse_set::{SparseSet, View, ViewMut};
+use crate::storage::{EntitiesView, EntityId};
+use std::alloc::Layout;
+
+/// Gives access to the storages of components registered at runtime.
+///
+/// Each storage is borrowed individually with `view` or `view_mut`.
+pub struct DynamicStoragesView<'a>(pub(super) &'a DynamicStorages);
+
+impl<'a> DynamicStoragesView<'a> {
+    /// Returns the id of the first component registered under `name`.
+    pub fn id(&self, name: &str) -> Option<DynamicId> {
+        self.0
+            .components
+            .iter()
+            .position(|component| component.name == name)
+            .map(DynamicId)
+    }
+    /// Immutably borrows the storage of `id`.
+    pub fn try_view(&self, id: DynamicId) -> Result<DynamicView<'a>, error::Borrow> {
+        Ok(DynamicView {
+            component: &self.0.components[id.0],
+            sparse_set: self.0.sparse_sets[id.0].try_borrow()?,
+        })
+    }
+    /// Immutably borrows the storage of `id`.
+    ///
+    /// Unwraps errors.
+    pub fn view(&self, id: DynamicId) -> DynamicView<'a> {
+        self.try_view(id).unwrap()
+    }
+    /// Mutably borrows the storage of `id`.
+    pub fn try_view_mut(&self, id: DynamicId) -> Result<DynamicViewMut<'a>, error::Borrow> {
+        Ok(DynamicViewMut {
+            component: &self.0.components[id.0],
+            sparse_set: self.0.sparse_sets[id.0].try_borrow_mut()?,
+        })
+    }
+    /// Mutably borrows the storage of `id`.
+    ///
+    /// Unwraps errors.
+    pub fn view_mut(&self, id: DynamicId) -> DynamicViewMut<'a> {
+        self.try_view_mut(id).unwrap()
+    }
+}
+
+/// Shared view over a storage of components registered at runtime.
+pub struct DynamicView<'a> {
+    component: &'a DynamicComponent,
+    sparse_set: Ref<'a, SparseSet<DynamicData>>,
+}
+
+impl DynamicView<'_> {
+    /// Returns the name the component was registered with.
+    pub fn name(&self) -> &str {
+        &self.component.name
+    }
+    /// Returns the layout the component was registered with.
+    pub fn layout(&self) -> Layout {
+        self.component.layout
+    }
+    /// Returns the number of components in the storage.
+    pub fn len(&self) -> usize {
+        self.sparse_set.len()
+    }
+    /// Returns true if the storage is empty.
+    pub fn is_empty(&self) -> bool {
+        self.sparse_set.len() == 0
+    }
+    /// Returns true if `entity` has a component in this storage.
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.sparse_set.contains(entity)
+    }
+    /// Returns the bytes of `entity`'s component.
+    pub fn get(&self, entity: EntityId) -> Option<&[u8]> {
+        self.sparse_set.get(entity).map(|component| &**component)
+    }
+    /// Returns an iterator over all components and their entity, in storage order.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &[u8])> {
+        let view = self.sparse_set.view();
+        view.dense
+            .iter()
+            .copied()
+            .zip(view.data.iter().map(|component| &**component))
+    }
+    /// Returns a view of the storage, it can be iterated together with other storages.
+    pub fn view(&self) -> View<'_, DynamicData> {
+        self.sparse_set.view()
+    }
+}
+
+/// Exclusive view over a storage of components registered at runtime.
+pub struct DynamicViewMut<'a> {
+    component: &'a DynamicComponent,
+    sparse_set: RefMut<'a, SparseSet<DynamicData>>,
+}
+
+impl DynamicViewMut<'_> {
+    /// Returns the name the component was registered with.
+    pub fn name(&self) -> &str {
+        &self.component.name
+    }
+    /// Returns the layout the component was registered with.
+    pub fn layout(&self) -> Layout {
+        self.component.layout
+    }
+    /// Returns the number of components in the storage.
+    pub fn len(&self) -> usize {
+        self.sparse_set.len()
+    }
+    /// Returns true if the storage is empty.
+    pub fn is_empty(&self) -> bool {
+        self.sparse_set.len() == 0
+    }
+    /// Returns true if `entity` has a component in this storage.
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.sparse_set.contains(entity)
+    }
+    /// Returns the bytes of `entity`'s component.
+    pub fn get(&self, entity: EntityId) -> Option<&[u8]> {
+        self.sparse_set.get(entity).map(|component| &**component)
+    }
+    /// Returns the bytes of `entity`'s component.
+    pub fn get_mut(&mut self, entity: EntityId) -> Option<&mut [u8]> {
+        self.sparse_set
+            .get_mut(entity)
+            .map(|component| &mut **component)
+    }
+    /// Returns an iterator over all components and their entity, in storage order.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &[u8])> {
+        let view = self.sparse_set.view();
+        view.dense
+            .iter()
+            .copied()
+            .zip(view.data.iter().map(|component| &**component))
+    }
+    /// Returns an iterator over all components and their entity, in storage order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, &mut [u8])> {
+        let ViewMut { dense, data, .. } = self.sparse_set.view_mut();
+        dense
+            .iter()
+            .copied()
+            .zip(data.iter_mut().map(|component| &mut **component))
+    }
+    /// Returns a view of the storage, it can be iterated together with other storages.
+    pub fn view(&self) -> View<'_, DynamicData> {
+        self.sparse_set.view()
+    }
+    /// Returns a view of the storage, it can be iterated together with other storages.
+    pub fn view_mut(&mut self) -> ViewMut<'_, DynamicData> {
+        self.sparse_set.view_mut()
+    }
+    /// Copies `component` into the storage and attaches it to `entity`.\
+    /// If `entity` already had this component, the previous one is dropped.
+    /// # Panics
+    /// `component` has to be exactly as long as the layout's size.
+    pub fn try_add_component(
+        &mut self,
+        component: &[u8],
+        entity: EntityId,
+        entities: &EntitiesView,
+    ) -> Result<(), error::AddComponent> {
+        assert_eq!(
+            component.len(),
+            self.component.layout.size(),
+            "Component of {:?} has to be {} bytes long.",
+            self.component.name,
+            self.component.layout.size()
+        );
+
+        if entities.is_alive(entity) {
+            self.sparse_set.view_mut().insert(
+                DynamicData::new(component, self.component.drop.clone()),
+                entity,
+            );
+            Ok(())
+        } else {
+            Err(error::AddComponent::EntityIsNotAlive)
+        }
+    }
+    /// Copies `component` into the storage and attaches it to `entity`.\
+    /// If `entity` already had this component, the previous one is dropped.
+    ///
+    /// Unwraps errors.
+    pub fn add_component(&mut self, component: &[u8], entity: EntityId, entities: &EntitiesView) {
+        self.try_add_component(component, entity, entities).unwrap()
+    }
+    /// Removes `entity`'s component and returns its bytes.\
+    /// The drop function isn't called, the caller becomes responsible for the component.
+    pub fn remove(&mut self, entity: EntityId) -> Option<Vec<u8>> {
+        self.sparse_set
+            .view_mut()
+            .remove(entity)
+            .map(DynamicData::into_bytes)
+    }
+    /// Drops `entity`'s component, returns true if there was one.
+    pub fn delete(&mut self, entity: EntityId) -> bool {
+        if self.sparse_set.contains(entity) {
+            self.sparse_set.view_mut().delete(entity);
+            true
+        } else {
+            false
+        }
+    }
+    /// Drops all components in the storage.
+    pub fn clear(&mut self) {
+        self.sparse_set.view_mut().clear()
+    }
+}
@@ -1,47 +1,87 @@
 #[cfg(feature = "serialization")]
 mod serialization;
 
+use std::cmp::Ordering;
 use std::num::NonZeroU64;
 
-/// A Key is a handle to an entity and has two parts, the index and the version.
-/// The index is 48 bits long and the version 16.
-#[doc(hidden)]
+/// Handle to an entity, it has two parts: the index and the version.\
+/// By default the index is 48 bits long and the version 16,
+/// with the `version_32` feature both are 32 bits long.
+///
+/// When an entity is deleted its index can be reused with a bumped version,
+/// old `EntityId`s then no longer match the entity.\
+/// Once the version reached its maximum, the index isn't reused anymore
+/// unless `World::enable_version_wraparound` was called.
+///
+/// `EntityId`s are ordered by index then version.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// let entity = EntityId::new_from_pair(5, 1);
+///
+/// assert_eq!(entity.index(), 5);
+/// assert_eq!(entity.version(), 1);
+/// assert_eq!(EntityId::from_bits(entity.to_bits()), Some(entity));
+/// assert!(EntityId::new(4) < entity);
+/// ```
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct EntityId(pub(super) NonZeroU64);
 
 impl EntityId {
     // Number of bits used by the version
-    const VERSION_LEN: u64 = 16;
+    #[cfg(not(feature = "version_32"))]
+    pub(super) const VERSION_LEN: u64 = 16;
+    #[cfg(feature = "version_32")]
+    pub(super) const VERSION_LEN: u64 = 32;
     const INDEX_MASK: u64 = !0 >> Self::VERSION_LEN;
     const VERSION_MASK: u64 = !Self::INDEX_MASK;
 
     /// Returns the index part of the EntityId.
     #[inline]
-    pub(crate) fn index(self) -> usize {
+    pub fn index(self) -> usize {
         ((self.0.get() & Self::INDEX_MASK) - 1) as usize
     }
     /// Returns the version part of the EntityId.
     #[inline]
-    pub(crate) fn version(self) -> usize {
-        ((self.0.get() & Self::VERSION_MASK) >> (64 - Self::VERSION_LEN)) as usize
+    pub fn version(self) -> u32 {
+        ((self.0.get() & Self::VERSION_MASK) >> (64 - Self::VERSION_LEN)) as u32
     }
-    /// Make a new EntityId with the given index.
+    /// Make a new EntityId with the given index and version 0.
+    ///
+    /// # Panics
+    /// `index` has to be lower than 2^48 - 1, 2^32 - 1 with the `version_32` feature.
     #[inline]
-    pub(crate) fn new(index: u64) -> Self {
+    pub fn new(index: u64) -> Self {
         assert!(index < Self::INDEX_MASK);
         EntityId(unsafe { NonZeroU64::new_unchecked(index + 1) })
     }
-
-    /// Make a new EntityId with the given version and index.
-    #[cfg(feature = "serialization")]
+    /// Make a new EntityId with the given index and version.
+    ///
+    /// # Panics
+    /// `index` has to be lower than 2^48 - 1 and `version` has to fit in 16 bits.\
+    /// With the `version_32` feature, `index` has to be lower than 2^32 - 1.
     #[inline]
-    pub(crate) fn new_from_pair(index: u64, version: u16) -> Self {
+    pub fn new_from_pair(index: u64, version: u32) -> Self {
         assert!(index < Self::INDEX_MASK);
+        assert!((version as u64) < 1 << Self::VERSION_LEN);
         EntityId(unsafe {
             NonZeroU64::new_unchecked((index + 1) | ((version as u64) << (64 - Self::VERSION_LEN)))
         })
     }
+    /// Returns the raw representation of the EntityId.
+    ///
+    /// It can be turned back into an EntityId with `from_bits`.
+    #[inline]
+    pub fn to_bits(self) -> u64 {
+        self.0.get()
+    }
+    /// Makes an EntityId from its raw representation, as returned by `to_bits`.\
+    /// Returns `None` if `bits` is 0, no EntityId is represented this way.
+    #[inline]
+    pub fn from_bits(bits: u64) -> Option<Self> {
+        NonZeroU64::new(bits).map(EntityId)
+    }
 
     /// Modify the index.
     #[cfg(not(test))]
@@ -73,6 +113,11 @@ impl EntityId {
             Err(())
         }
     }
+    /// Sets the version back to 0.
+    #[inline]
+    pub(super) fn wrap_version(&mut self) {
+        self.0 = unsafe { NonZeroU64::new_unchecked(self.0.get() & Self::INDEX_MASK) }
+    }
     #[cfg(test)]
     pub(crate) fn zero() -> Self {
         EntityId(NonZeroU64::new(1).unwrap())
@@ -82,6 +127,18 @@ impl EntityId {
     }
 }
 
+impl Ord for EntityId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.index(), self.version()).cmp(&(other.index(), other.version()))
+    }
+}
+
+impl PartialOrd for EntityId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl std::fmt::Debug for EntityId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -110,3 +167,27 @@ fn entity_id() {
     assert_eq!(entity_id.index(), 554);
     assert_eq!(entity_id.version(), 3);
 }
+
+#[test]
+fn bits_and_ordering() {
+    let entity_id = EntityId::new_from_pair(701, 3);
+    assert_eq!(entity_id.index(), 701);
+    assert_eq!(entity_id.version(), 3);
+    assert_eq!(EntityId::from_bits(entity_id.to_bits()), Some(entity_id));
+    assert_eq!(EntityId::from_bits(0), None);
+
+    let mut entity_ids = vec![
+        EntityId::new_from_pair(2, 0),
+        EntityId::new_from_pair(1, 5),
+        EntityId::new_from_pair(1, 2),
+    ];
+    entity_ids.sort();
+    assert_eq!(
+        entity_ids,
+        vec![
+            EntityId::new_from_pair(1, 2),
+            EntityId::new_from_pair(1, 5),
+            EntityId::new_from_pair(2, 0),
+        ]
+    );
+}
@@ -1,6 +1,31 @@
 use super::EntityId;
+use serde::de::Error;
 use serde::{ser::SerializeTupleStruct, Deserialize, Deserializer, Serialize, Serializer};
+use std::num::NonZeroU64;
 
+impl EntityId {
+    /// Same as `new_from_pair` but returns `None` if `index` or `version` doesn't fit.
+    fn try_from_pair(index: u64, version: u64) -> Option<Self> {
+        if index < Self::INDEX_MASK && version < 1 << Self::VERSION_LEN {
+            Some(EntityId::new_from_pair(index, version as u32))
+        } else {
+            None
+        }
+    }
+}
+
+fn does_not_fit<E: Error>(index: u64, version: u64) -> E {
+    E::custom(format!(
+        "EntityId ({}, {}) doesn't fit in {} index bits and {} version bits",
+        index,
+        version,
+        64 - EntityId::VERSION_LEN,
+        EntityId::VERSION_LEN
+    ))
+}
+
+// Non human readable formats store the raw bits, like before the version length could change.
+// They can only be loaded with the same `version_32` setting they were saved with.
 impl Serialize for EntityId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -9,7 +34,7 @@ impl Serialize for EntityId {
         if serializer.is_human_readable() {
             let mut tup = serializer.serialize_tuple_struct("EntityId", 2)?;
             tup.serialize_field(&(self.index() as u64))?;
-            tup.serialize_field(&(self.version() as u16))?;
+            tup.serialize_field(&self.version())?;
             tup.end()
         } else {
             serializer.serialize_u64(self.0.get())
@@ -23,10 +48,16 @@ impl<'de> Deserialize<'de> for EntityId {
         D: Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
-            let (index, version): (u64, u16) = Deserialize::deserialize(deserializer)?;
-            Ok(EntityId::new_from_pair(index, version))
+            let (index, version): (u64, u32) = Deserialize::deserialize(deserializer)?;
+            EntityId::try_from_pair(index, version.into())
+                .ok_or_else(|| does_not_fit(index, version.into()))
         } else {
-            Ok(EntityId(Deserialize::deserialize(deserializer)?))
+            let bits: u64 = Deserialize::deserialize(deserializer)?;
+            if bits & EntityId::INDEX_MASK != 0 {
+                Ok(EntityId(NonZeroU64::new(bits).unwrap()))
+            } else {
+                Err(D::Error::custom("EntityId's index bits can't be 0"))
+            }
         }
     }
 }
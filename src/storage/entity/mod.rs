@@ -1,10 +1,15 @@
 mod add_component;
 mod entity_id;
+#[cfg(feature = "serialization")]
+mod serialization;
 mod view;
 
+use crate::sparse_set::SystemTicks;
 use crate::unknown_storage::UnknownStorage;
 pub use entity_id::EntityId;
-use std::any::TypeId;
+use std::any::{type_name, Any, TypeId};
+use std::mem::size_of;
+use std::sync::atomic::AtomicUsize;
 pub use view::{EntitiesView, EntitiesViewMut};
 
 /// Type used to borrow `Entities` mutably.
@@ -27,9 +32,15 @@ pub struct EntitiesMut;
 // Removed entities form a linked list inside the vector, using their index part to point to the next.
 // Removed entities are added to one end and removed from the other.
 // Dead entities are simply never added to the linked list.
+// Entities reserved through a shared borrow are counted by `reserved`, they take the indices
+// following `data` and are pushed to it when commands are applied, after each workload batch,
+// or the next time Entities is borrowed mutably.
 pub struct Entities {
     data: Vec<EntityId>,
     list: Option<(usize, usize)>,
+    reserved: AtomicUsize,
+    // recycle entities whose version reached its maximum, starting back at 0
+    wrap_versions: bool,
 }
 
 impl Default for Entities {
@@ -37,30 +48,70 @@ impl Default for Entities {
         Entities {
             data: Vec::new(),
             list: None,
+            reserved: AtomicUsize::new(0),
+            wrap_versions: false,
         }
     }
 }
 
 impl Entities {
     pub(crate) fn view(&self) -> EntitiesView {
-        EntitiesView { data: &self.data }
+        EntitiesView {
+            data: &self.data,
+            list: self.list,
+            reserved: &self.reserved,
+        }
     }
     pub(crate) fn view_mut(&mut self) -> EntitiesViewMut {
+        self.flush_reserved();
         EntitiesViewMut {
             data: &mut self.data,
             list: &mut self.list,
+            reserved: &self.reserved,
+            wrap_versions: self.wrap_versions,
         }
     }
+    /// Pushes entities reserved with a shared borrow to `data`.
+    pub(crate) fn flush_reserved(&mut self) {
+        let reserved = std::mem::replace(self.reserved.get_mut(), 0);
+        let len = self.data.len();
+        self.data
+            .extend((len..len + reserved).map(|index| EntityId::new(index as u64)));
+    }
+    pub(crate) fn enable_version_wraparound(&mut self) {
+        self.wrap_versions = true;
+    }
     pub(super) fn delete(&mut self, entity: EntityId) -> bool {
         self.view_mut().delete(entity)
     }
 }
 
 impl UnknownStorage for Entities {
-    fn delete(&mut self, _entity: EntityId) -> &[TypeId] {
+    fn delete(&mut self, _entity: EntityId, _ticks: SystemTicks) -> &[TypeId] {
         &[]
     }
+    fn delete_many(&mut self, _entities: &[EntityId], _ticks: SystemTicks) -> &[TypeId] {
+        &[]
+    }
+    fn clear(&mut self, _ticks: SystemTicks) {
+        self.view_mut().clear();
+    }
     fn unpack(&mut self, _entity: EntityId) {}
+    fn len(&self) -> usize {
+        self.view().len()
+    }
+    fn memory_usage(&self) -> usize {
+        size_of::<Self>() + self.data.capacity() * size_of::<EntityId>()
+    }
+    fn type_name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+    fn any(&self) -> &dyn Any {
+        self
+    }
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
 
 #[test]
@@ -94,11 +145,31 @@ fn entities() {
     assert_eq!(key02.index(), 0);
     assert_eq!(key02.version(), 2);
 
-    let last_key = EntityId(NonZeroU64::new(!(!0 >> 15) + 1).unwrap());
+    let last_key = EntityId(NonZeroU64::new(!(!0 >> (EntityId::VERSION_LEN - 1)) + 1).unwrap());
     entities.data[0] = last_key;
     assert!(entities.view_mut().delete(last_key));
+    assert!(!entities.view().is_alive(last_key));
     assert_eq!(entities.list, None);
     let dead = entities.view_mut().generate();
     assert_eq!(dead.index(), 2);
     assert_eq!(dead.version(), 0);
 }
+
+#[test]
+fn version_wraparound() {
+    use std::num::NonZeroU64;
+
+    let mut entities = Entities::default();
+    entities.enable_version_wraparound();
+
+    entities.view_mut().generate();
+    let last_key = EntityId(NonZeroU64::new(!(!0 >> (EntityId::VERSION_LEN - 1)) + 1).unwrap());
+    entities.data[0] = last_key;
+    assert!(entities.view_mut().delete(last_key));
+    assert!(!entities.view().is_alive(last_key));
+    assert_eq!(entities.list, Some((0, 0)));
+
+    let recycled = entities.view_mut().generate();
+    assert_eq!(recycled.index(), 0);
+    assert_eq!(recycled.version(), 0);
+}
@@ -0,0 +1,52 @@
+use super::{Entities, EntityId};
+use serde::de::Error;
+use serde::{ser::SerializeTupleStruct, Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::atomic::Ordering;
+
+impl Serialize for Entities {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tup = serializer.serialize_tuple_struct("Entities", 2)?;
+        // reserved entities are serialized as regular ones
+        if self.reserved.load(Ordering::Relaxed) == 0 {
+            tup.serialize_field(&self.data)?;
+        } else {
+            let data: Vec<_> = self
+                .data
+                .iter()
+                .copied()
+                .chain(self.view().reserved_iter())
+                .collect();
+            tup.serialize_field(&data)?;
+        }
+        tup.serialize_field(&self.list)?;
+        tup.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Entities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (data, list): (Vec<EntityId>, Option<(usize, usize)>) =
+            Deserialize::deserialize(deserializer)?;
+
+        if let Some((new, old)) = list {
+            if new >= data.len() || old >= data.len() {
+                return Err(D::Error::custom(
+                    "Entities' removed list points outside of the entities.",
+                ));
+            }
+        }
+
+        Ok(Entities {
+            data,
+            list,
+            reserved: Default::default(),
+            wrap_versions: false,
+        })
+    }
+}
@@ -1,18 +1,94 @@
 use super::add_component::AddComponent;
 use super::EntityId;
 use crate::error;
-use crate::sparse_set::ViewAddEntity;
+use crate::sparse_set::{ViewAddEntity, ViewBulkAddEntity};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// View into the entities.
 pub struct EntitiesView<'a> {
     pub(super) data: &'a [EntityId],
+    pub(super) list: Option<(usize, usize)>,
+    pub(super) reserved: &'a AtomicUsize,
 }
 
-impl EntitiesView<'_> {
+impl<'a> EntitiesView<'a> {
     /// Returns true if the EntityId matches a living entity.
-    pub(crate) fn is_alive(&self, entity_id: EntityId) -> bool {
-        entity_id.index() < self.data.len()
-            && entity_id == unsafe { *self.data.get_unchecked(entity_id.index()) }
+    pub fn is_alive(&self, entity_id: EntityId) -> bool {
+        if entity_id.index() < self.data.len() {
+            entity_id == unsafe { *self.data.get_unchecked(entity_id.index()) }
+        } else {
+            entity_id.version() == 0
+                && entity_id.index() < self.data.len() + self.reserved.load(Ordering::Relaxed)
+        }
+    }
+    /// Reserves a new entity and returns its `EntityId`.
+    ///
+    /// Only needs a shared borrow of `Entities` so it can be called from systems running in parallel.\
+    /// The entity is alive right away, components can be added to it.
+    /// Reserved entities never reuse the index of a deleted entity.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize,)>();
+    ///
+    /// world.run::<(Entities, &mut usize), _, _>(|(entities, mut usizes)| {
+    ///     let entity = entities.reserve_entity();
+    ///     assert!(entities.is_alive(entity));
+    ///
+    ///     entities.add_component(&mut usizes, 0, entity);
+    ///     assert_eq!(usizes.get(entity), Some(&0));
+    /// });
+    /// ```
+    pub fn reserve_entity(&self) -> EntityId {
+        let index = self.data.len() + self.reserved.fetch_add(1, Ordering::Relaxed);
+        EntityId::new(index as u64)
+    }
+    /// Reserves `count` new entities and returns an iterator over their `EntityId`.
+    ///
+    /// See [reserve_entity].
+    ///
+    /// [reserve_entity]: struct.EntitiesView.html#method.reserve_entity
+    pub fn reserve_entities(&self, count: usize) -> impl Iterator<Item = EntityId> {
+        let start = self.data.len() + self.reserved.fetch_add(count, Ordering::Relaxed);
+        (start..start + count).map(|index| EntityId::new(index as u64))
+    }
+    /// Returns an iterator over all living entities, in index order.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize,)>();
+    ///
+    /// world.run::<EntitiesMut, _, _>(|mut entities| {
+    ///     let entity0 = entities.add_entity((), ());
+    ///     let entity1 = entities.add_entity((), ());
+    ///
+    ///     assert_eq!(entities.iter().collect::<Vec<_>>(), vec![entity0, entity1]);
+    /// });
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = EntityId> + 'a {
+        // the last entity of the removed list still has its own index
+        let last_removed = self.list.map(|(new, _)| new);
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |&(index, entity_id)| {
+                entity_id.index() == index && Some(index) != last_removed
+            })
+            .map(|(_, &entity_id)| entity_id)
+            .chain(self.reserved_iter())
+    }
+    pub(super) fn reserved_iter(&self) -> impl Iterator<Item = EntityId> + 'a {
+        let len = self.data.len();
+        (len..len + self.reserved.load(Ordering::Relaxed)).map(|index| EntityId::new(index as u64))
+    }
+    /// Returns the number of living entities.\
+    /// Entities are counted, this is linear in the number of entities ever created.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+    /// Returns true if there isn't any living entity.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
     }
     pub fn try_add_component<C, S: AddComponent<C>>(
         &self,
@@ -38,10 +114,13 @@ impl EntitiesView<'_> {
 pub struct EntitiesViewMut<'a> {
     pub(super) data: &'a mut Vec<EntityId>,
     pub(crate) list: &'a mut Option<(usize, usize)>,
+    // always 0, reservations are flushed before creating the view
+    pub(super) reserved: &'a AtomicUsize,
+    pub(super) wrap_versions: bool,
 }
 
 impl EntitiesViewMut<'_> {
-    pub(super) fn generate(&mut self) -> EntityId {
+    pub(crate) fn generate(&mut self) -> EntityId {
         let index = self.list.map(|(_, old)| old);
         if let Some((new, ref mut old)) = self.list {
             if *new == *old {
@@ -59,19 +138,41 @@ impl EntitiesViewMut<'_> {
             entity_id
         }
     }
+    /// Reserves capacity for at least `additional` more entities.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
     /// Returns true if the EntityId matches a living entity.
-    fn is_alive(&self, entity_id: EntityId) -> bool {
+    pub fn is_alive(&self, entity_id: EntityId) -> bool {
         self.as_non_mut().is_alive(entity_id)
     }
+    /// Returns an iterator over all living entities, in index order.
+    pub fn iter(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.as_non_mut().iter()
+    }
+    /// Returns the number of living entities.\
+    /// Entities are counted, this is linear in the number of entities ever created.
+    pub fn len(&self) -> usize {
+        self.as_non_mut().len()
+    }
+    /// Returns true if there isn't any living entity.
+    pub fn is_empty(&self) -> bool {
+        self.as_non_mut().is_empty()
+    }
     /// Delete an entity, returns true if the entity was alive.
     pub(super) fn delete(&mut self, entity_id: EntityId) -> bool {
         if self.is_alive(entity_id) {
-            if unsafe {
-                self.data
-                    .get_unchecked_mut(entity_id.index())
-                    .bump_version()
-                    .is_ok()
-            } {
+            let slot = unsafe { self.data.get_unchecked_mut(entity_id.index()) };
+            let recycle = if slot.bump_version().is_ok() {
+                true
+            } else if self.wrap_versions {
+                slot.wrap_version();
+                true
+            } else {
+                false
+            };
+
+            if recycle {
                 if let Some((ref mut new, _)) = self.list {
                     unsafe {
                         self.data
@@ -82,12 +183,22 @@ impl EntitiesViewMut<'_> {
                 } else {
                     *self.list = Some((entity_id.index(), entity_id.index()));
                 }
+            } else {
+                // the entity can't be recycled anymore, make sure it's never considered alive again
+                unsafe { *self.data.get_unchecked_mut(entity_id.index()) = EntityId::dead() };
             }
             true
         } else {
             false
         }
     }
+    /// Deletes all living entities.
+    pub(crate) fn clear(&mut self) {
+        let alive: Vec<_> = self.iter().collect();
+        for entity_id in alive {
+            self.delete(entity_id);
+        }
+    }
     /// Stores `component` in a new entity, the `EntityId` to this entity is returned.
     ///
     /// Multiple components can be added at the same time using a tuple.
@@ -112,8 +223,40 @@ impl EntitiesViewMut<'_> {
         storages.add_entity(component, entity_id);
         entity_id
     }
-    fn as_non_mut(&self) -> EntitiesView {
-        EntitiesView { data: self.data }
+    /// Creates one entity per item of the shortest column, columns are zipped like `Iterator::zip`.\
+    /// Returns the `EntityId`s of the new entities in order.
+    ///
+    /// Storages only have to grow once and packs are respected just like with [add_entity].
+    /// # Example:
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize, u32)>();
+    ///
+    /// world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(|(mut entities, mut usizes, mut u32s)| {
+    ///     let new_entities = entities.bulk_add_entity(
+    ///         (&mut usizes, &mut u32s),
+    ///         (vec![0, 1, 2], std::iter::repeat(3).take(3)),
+    ///     );
+    ///
+    ///     assert_eq!(new_entities.len(), 3);
+    ///     assert_eq!(usizes.get(new_entities[2]), Some(&2));
+    ///     assert_eq!(u32s.get(new_entities[2]), Some(&3));
+    /// });
+    /// ```
+    /// [add_entity]: struct.EntitiesViewMut.html#method.add_entity
+    pub fn bulk_add_entity<C, T: ViewBulkAddEntity<C>>(
+        &mut self,
+        storages: T,
+        components: C,
+    ) -> Vec<EntityId> {
+        storages.bulk_add_entity(components, self)
+    }
+    pub(crate) fn as_non_mut(&self) -> EntitiesView {
+        EntitiesView {
+            data: self.data,
+            list: *self.list,
+            reserved: self.reserved,
+        }
     }
     pub fn try_add_component<C, S: AddComponent<C>>(
         &self,
@@ -1,84 +1,103 @@
 mod all;
+mod dynamic;
 mod entity;
 
-pub use all::{AllStorages, AllStoragesViewMut};
+pub use all::{AllStorages, AllStoragesViewMut, StorageInfo};
+pub use dynamic::{
+    DynamicComponent, DynamicData, DynamicId, DynamicStorages, DynamicStoragesView, DynamicView,
+    DynamicViewMut, SharedDynamicStorages,
+};
 pub use entity::{Entities, EntitiesMut, EntitiesView, EntitiesViewMut, EntityId};
 
 use crate::atomic_refcell::{AtomicRefCell, Ref, RefMut};
 use crate::error;
-use crate::sparse_set::SparseSet;
+use crate::sparse_set::{SparseSet, SystemTicks};
 use crate::unknown_storage::UnknownStorage;
-use std::any::{Any, TypeId};
+use std::any::TypeId;
 
 /// Abstract away `T` from `AtomicRefCell<T>` to be able to store
 /// different types in a `HashMap<TypeId, Storage>`.\
-/// `unknown` is the address of the vtable part of the storage's `UnknownStorage` implementation.
+/// The concrete type can be recovered with `UnknownStorage::any`.
 pub(crate) struct Storage {
-    pub(super) container: AtomicRefCell<Box<dyn Any + Send + Sync>>,
-    pub(super) unknown: *const (),
+    pub(super) container: AtomicRefCell<Box<dyn UnknownStorage>>,
 }
 
 impl Storage {
     /// Creates a new `Storage` storing elements of type T.
     pub(crate) fn new<T: 'static + Send + Sync>() -> Self {
-        let sparse_set = SparseSet::<T>::default();
-        // store the vtable of this trait object
-        // for a full explanation see UnknownStorage documentation
-        let unknown: [*const (); 2] = unsafe {
-            *(&(&sparse_set as &dyn UnknownStorage as *const _) as *const *const _
-                as *const [*const _; 2])
-        };
+        Storage::from_container(SparseSet::<T>::default())
+    }
+    /// Creates a new `Storage` wrapping `container`.
+    pub(super) fn from_container<S: UnknownStorage>(container: S) -> Self {
         Storage {
-            container: AtomicRefCell::new(Box::new(sparse_set)),
-            unknown: unknown[1],
+            container: AtomicRefCell::new(Box::new(container)),
         }
     }
     /// Immutably borrows the component container.
     pub(crate) fn sparse_set<T: 'static>(&self) -> Result<Ref<SparseSet<T>>, error::Borrow> {
         Ok(Ref::map(self.container.try_borrow()?, |sparse_set| {
-            sparse_set.downcast_ref().unwrap()
+            sparse_set.any().downcast_ref().unwrap()
         }))
     }
     /// Mutably borrows the component container.
     pub(crate) fn sparse_set_mut<T: 'static>(&self) -> Result<RefMut<SparseSet<T>>, error::Borrow> {
         Ok(RefMut::map(
             self.container.try_borrow_mut()?,
-            |sparse_set| sparse_set.downcast_mut().unwrap(),
+            |sparse_set| sparse_set.any_mut().downcast_mut().unwrap(),
         ))
     }
     /// Immutably borrows entities' storage.
     pub(crate) fn entities(&self) -> Result<Ref<Entities>, error::Borrow> {
         Ok(Ref::map(self.container.try_borrow()?, |entities| {
-            entities.downcast_ref().unwrap()
+            entities.any().downcast_ref().unwrap()
         }))
     }
     /// Mutably borrows entities' storage.
     pub(crate) fn entities_mut(&self) -> Result<RefMut<Entities>, error::Borrow> {
         Ok(RefMut::map(self.container.try_borrow_mut()?, |entities| {
-            entities.downcast_mut().unwrap()
+            entities.any_mut().downcast_mut().unwrap()
+        }))
+    }
+    /// Immutably borrows the storages of components registered at runtime.
+    pub(crate) fn dynamic_storages(&self) -> Result<Ref<DynamicStorages>, error::Borrow> {
+        Ok(Ref::map(self.container.try_borrow()?, |storages| {
+            storages.any().downcast_ref().unwrap()
+        }))
+    }
+    /// Mutably borrows the storages of components registered at runtime.
+    pub(crate) fn dynamic_storages_mut(&self) -> Result<RefMut<DynamicStorages>, error::Borrow> {
+        Ok(RefMut::map(self.container.try_borrow_mut()?, |storages| {
+            storages.any_mut().downcast_mut().unwrap()
         }))
     }
-    /// Mutably borrows the container and delete `index`.
-    pub(crate) fn delete(&mut self, entity: EntityId) -> Result<&[TypeId], error::Borrow> {
-        // reconstruct a `dyn UnknownStorage` from two pointers
-        // for a full explanation see UnknownStorage documentation
-        let container: RefMut<Box<dyn Any + Send + Sync>> = self.container.try_borrow_mut()?;
-        let container = &**container as *const dyn Any as *const ();
-        let unknown: &mut dyn UnknownStorage = unsafe {
-            &mut **(&[container, self.unknown] as *const _ as *const *mut dyn UnknownStorage)
-        };
-        Ok(unknown.delete(entity))
+    /// Deletes `entity`'s component.\
+    /// Returns the storages observing this one.
+    pub(crate) fn delete(&mut self, entity: EntityId, ticks: SystemTicks) -> &[TypeId] {
+        self.container.get_mut().delete(entity, ticks)
+    }
+    /// Deletes the components of all `entities`.\
+    /// Returns the storages observing this one.
+    pub(crate) fn delete_many(&mut self, entities: &[EntityId], ticks: SystemTicks) -> &[TypeId] {
+        self.container.get_mut().delete_many(entities, ticks)
+    }
+    /// Removes all components.
+    pub(crate) fn clear(&mut self, ticks: SystemTicks) {
+        self.container.get_mut().clear(ticks)
+    }
+    pub(crate) fn unpack(&mut self, entity: EntityId) {
+        self.container.get_mut().unpack(entity)
+    }
+    /// Returns the number of components in the storage.
+    pub(crate) fn len(&mut self) -> usize {
+        self.container.get_mut().len()
+    }
+    /// Returns the number of bytes used by the storage, heap allocations included.
+    pub(crate) fn memory_usage(&mut self) -> usize {
+        self.container.get_mut().memory_usage()
     }
-    pub(crate) fn unpack(&mut self, entity: EntityId) -> Result<(), error::Borrow> {
-        // reconstruct a `dyn UnknownStorage` from two pointers
-        // for a full explanation see UnknownStorage documentation
-        let container: RefMut<Box<dyn Any + Send + Sync>> = self.container.try_borrow_mut()?;
-        let container = &**container as *const dyn Any as *const ();
-        let unknown: &mut dyn UnknownStorage = unsafe {
-            &mut **(&[container, self.unknown] as *const _ as *const *mut dyn UnknownStorage)
-        };
-        unknown.unpack(entity);
-        Ok(())
+    /// Returns the name of the type stored.
+    pub(crate) fn type_name(&mut self) -> &'static str {
+        self.container.get_mut().type_name()
     }
 }
 
@@ -105,7 +124,7 @@ fn delete() {
         .view_mut()
         .insert("test1", entity_id);
     entity_id.set_index(5);
-    storage.delete(entity_id).unwrap();
+    storage.delete(entity_id, SystemTicks::default());
     assert_eq!(storage.sparse_set::<&str>().unwrap().get(entity_id), None);
     entity_id.set_index(10);
     assert_eq!(
@@ -118,9 +137,9 @@ fn delete() {
         Some(&"test1")
     );
     entity_id.set_index(10);
-    storage.delete(entity_id).unwrap();
+    storage.delete(entity_id, SystemTicks::default());
     entity_id.set_index(1);
-    storage.delete(entity_id).unwrap();
+    storage.delete(entity_id, SystemTicks::default());
     entity_id.set_index(5);
     assert_eq!(storage.sparse_set::<&str>().unwrap().get(entity_id), None);
     entity_id.set_index(10);
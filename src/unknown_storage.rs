@@ -1,36 +1,59 @@
-use crate::sparse_set::SparseSet;
+use crate::sparse_set::{SparseSet, SystemTicks};
 use crate::storage::EntityId;
-use std::any::TypeId;
+use std::any::{type_name, Any, TypeId};
 
 // When removing an entity all its components have to be removed.
-// These components are stored in HashMap<TypeId, Box<dyn Any>> to be able to store multiple types in the HashMap.
-// However we can't get back the concrete type of Any and delete the component.
-// This is where this trait comes into play.
-// It is implemented for all SparseSet and a specific version will be created by the compiler when needed.
-// We then store the vtable part of the trait object specific for the type added to the World.
-// We do so by taking a pointer to the trait object's fat pointer,
-// cast it to *const [usize; 2] and storring the second usize.
-// This happens in Storage::new.
-// Then when we want to delete a component we re-assemble the trait object at runtime.
-// We do so by taking a reference to the SparseSet, cast it to a thin pointer.
-// Put it in an array with the stored vtable pointer and take a pointer to this array.
-// The pointer is then cast to a reference to the fat pointer of a Delete trait object.
-// This happens in Storage::delete.
-//
-// All of this is temporary, the std will provide a way to get the vtable of a trait object
-// in the future. Until then this hack works as long as trait objects' fat pointer don't
-// change representation.
-pub(super) trait UnknownStorage {
-    fn delete(&mut self, entity: EntityId) -> &[TypeId];
+// These components are stored in HashMap<TypeId, Storage> to be able to store multiple types in the HashMap.
+// Each storage is boxed as a `dyn UnknownStorage`,
+// this trait holds all operations that don't need to know the concrete type of the storage.
+// When the concrete type is known, `any` and `any_mut` are used to downcast the storage.
+pub(super) trait UnknownStorage: Send + Sync + 'static {
+    fn delete(&mut self, entity: EntityId, ticks: SystemTicks) -> &[TypeId];
+    fn delete_many(&mut self, entities: &[EntityId], ticks: SystemTicks) -> &[TypeId];
+    fn clear(&mut self, ticks: SystemTicks);
     fn unpack(&mut self, entitiy: EntityId);
+    fn len(&self) -> usize;
+    /// Bytes used by the storage, heap allocations included.
+    fn memory_usage(&self) -> usize;
+    fn type_name(&self) -> &'static str;
+    fn any(&self) -> &dyn Any;
+    fn any_mut(&mut self) -> &mut dyn Any;
 }
 
-impl<T: 'static> UnknownStorage for SparseSet<T> {
-    fn delete(&mut self, entity: EntityId) -> &[TypeId] {
-        self.remove(entity);
+impl<T: 'static + Send + Sync> UnknownStorage for SparseSet<T> {
+    fn delete(&mut self, entity: EntityId, ticks: SystemTicks) -> &[TypeId] {
+        self.view_mut_with_ticks(ticks).delete(entity);
         &self.pack_info.observer_types
     }
+    fn delete_many(&mut self, entities: &[EntityId], ticks: SystemTicks) -> &[TypeId] {
+        let mut view = self.view_mut_with_ticks(ticks);
+        for &entity in entities {
+            view.delete(entity);
+        }
+        &self.pack_info.observer_types
+    }
+    fn clear(&mut self, ticks: SystemTicks) {
+        // unique components don't belong to any entity
+        if !self.is_unique() {
+            self.view_mut_with_ticks(ticks).clear();
+        }
+    }
     fn unpack(&mut self, entity: EntityId) {
         Self::unpack(self, entity);
     }
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+    fn memory_usage(&self) -> usize {
+        Self::memory_usage(self)
+    }
+    fn type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+    fn any(&self) -> &dyn Any {
+        self
+    }
+    fn any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
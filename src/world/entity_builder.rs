@@ -0,0 +1,138 @@
+use crate::error;
+use crate::storage::{EntitiesMut, EntityId};
+use crate::world::World;
+use std::any::{type_name, TypeId};
+
+/// Adds an entity one component at a time.
+///
+/// Missing storages are registered when the entity is built
+/// and packs are respected just like with `EntitiesViewMut::add_entity`.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// let world = World::default();
+///
+/// let entity = world.entity_builder().with(0usize).with(1u32).build();
+///
+/// world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+///     assert_eq!((&usizes, &u32s).get(entity), Some((&0, &1)));
+/// });
+/// ```
+pub struct EntityBuilder<'a, T> {
+    world: &'a World,
+    components: T,
+}
+
+impl<'a> EntityBuilder<'a, ()> {
+    pub(super) fn new(world: &'a World) -> Self {
+        EntityBuilder {
+            world,
+            components: (),
+        }
+    }
+    /// Adds `component` to the entity.
+    pub fn with<C: 'static + Send + Sync>(self, component: C) -> EntityBuilder<'a, (C,)> {
+        EntityBuilder {
+            world: self.world,
+            components: (component,),
+        }
+    }
+}
+
+impl<T: BuildEntity> EntityBuilder<'_, T> {
+    /// Creates the entity and returns its `EntityId`.
+    ///
+    /// Fails if a component type was added more than once.
+    pub fn try_build(self) -> Result<EntityId, error::BuildEntity> {
+        self.components.try_build(self.world)
+    }
+    /// Creates the entity and returns its `EntityId`.
+    ///
+    /// Unwraps errors.
+    pub fn build(self) -> EntityId {
+        self.try_build().unwrap()
+    }
+}
+
+/// Components an `EntityBuilder` can add.
+pub trait BuildEntity {
+    /// Registers the components' storages if needed then adds an entity with these components to `world`.
+    ///
+    /// Fails if a component type is present more than once.
+    fn try_build(self, world: &World) -> Result<EntityId, error::BuildEntity>;
+}
+
+impl BuildEntity for () {
+    fn try_build(self, world: &World) -> Result<EntityId, error::BuildEntity> {
+        Ok(world.try_run::<EntitiesMut, _, _>(|mut entities| entities.add_entity((), ()))?)
+    }
+}
+
+macro_rules! impl_build_entity {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: 'static + Send + Sync),+> BuildEntity for ($($type,)+) {
+            fn try_build(self, world: &World) -> Result<EntityId, error::BuildEntity> {
+                // the same storage can't be borrowed mutably twice
+                let type_ids = [$(TypeId::of::<$type>()),+];
+                let type_names = [$(type_name::<$type>()),+];
+                for (i, type_id) in type_ids.iter().enumerate() {
+                    if type_ids[..i].contains(type_id) {
+                        return Err(error::BuildEntity::DuplicateComponent(type_names[i]));
+                    }
+                }
+
+                {
+                    let mut all_storages = world
+                        .storages
+                        .try_borrow_mut()
+                        .map_err(error::GetStorage::AllStoragesBorrow)?;
+                    $(
+                        all_storages.register::<$type>();
+                    )+
+                }
+
+                Ok(world.try_run::<(EntitiesMut, ($(&mut $type,)+)), _, _>(|(mut entities, mut storages)| {
+                    entities.add_entity(($(&mut storages.$index,)+), self)
+                })?)
+            }
+        }
+    }
+}
+
+macro_rules! build_entity {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_build_entity![$(($type, $index))*];
+        build_entity![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_build_entity![$(($type, $index))*];
+    }
+}
+
+build_entity![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+
+macro_rules! impl_with {
+    ($($type: ident)+) => {
+        impl<'a, $($type),+> EntityBuilder<'a, ($($type,)+)> {
+            /// Adds `component` to the entity.
+            #[allow(non_snake_case)]
+            pub fn with<Z: 'static + Send + Sync>(self, component: Z) -> EntityBuilder<'a, ($($type,)+ Z)> {
+                let ($($type,)+) = self.components;
+                EntityBuilder {
+                    world: self.world,
+                    components: ($($type,)+ component),
+                }
+            }
+        }
+    }
+}
+
+macro_rules! with {
+    ($($type: ident)*; $type1: ident $($queue_type: ident)*) => {
+        impl_with![$($type)* $type1];
+        with![$($type)* $type1; $($queue_type)*];
+    };
+    ($($type: ident)*;) => {}
+}
+
+with![; A B C D E F G H I];
@@ -1,18 +1,34 @@
+mod entity_builder;
+mod ordering;
 mod pack;
 mod pipeline;
 mod register;
+#[cfg(feature = "serialization")]
+mod serialization;
+mod workload_builder;
+mod workload_info;
 
 use crate::atomic_refcell::AtomicRefCell;
+use crate::commands::CommandQueue;
 use crate::error;
+use crate::events::EventQueue;
 use crate::run::Run;
-use crate::sparse_set::{Pack, UpdatePack};
-use crate::storage::AllStorages;
+use crate::sparse_set::{Hooks, Pack, SystemTicks, UpdatePack};
+use crate::storage::{AllStorages, DynamicComponent, DynamicId, DynamicStorages, Entities};
+use crate::Unique;
+pub use entity_builder::{BuildEntity, EntityBuilder};
+pub use ordering::{Ordered, SystemOrdering};
 use pack::{LoosePack, TightPack};
+pub use pipeline::WorkloadSystem;
 use pipeline::{Pipeline, Workload};
 #[cfg(feature = "parallel")]
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use register::Register;
 use std::marker::PhantomData;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU32, Ordering};
+pub use workload_builder::WorkloadBuilder;
+pub use workload_info::{BatchInfo, Conflict, SystemInfo, WorkloadInfo};
 
 /// Holds all components and keeps track of entities and what they own.
 pub struct World {
@@ -20,6 +36,10 @@ pub struct World {
     #[cfg(feature = "parallel")]
     pub(crate) thread_pool: ThreadPool,
     pipeline: AtomicRefCell<Pipeline>,
+    // last tick given to a system, used for change detection
+    tick: AtomicU32,
+    // last run of the workload system which ran the longest time ago
+    oldest_run: AtomicU32,
     _not_send: PhantomData<*const ()>,
 }
 
@@ -37,6 +57,8 @@ impl Default for World {
                 .build()
                 .unwrap(),
             pipeline: AtomicRefCell::new(Default::default()),
+            tick: AtomicU32::new(0),
+            oldest_run: AtomicU32::new(0),
             _not_send: PhantomData,
         }
     }
@@ -86,6 +108,8 @@ impl World {
                 .build()
                 .unwrap(),
             pipeline: AtomicRefCell::new(Default::default()),
+            tick: AtomicU32::new(0),
+            oldest_run: AtomicU32::new(0),
             _not_send: PhantomData,
         }
     }
@@ -128,6 +152,44 @@ impl World {
         self.storages.try_borrow_mut()?.register_unique(component);
         Ok(())
     }
+    /// Returns a builder adding an entity one component at a time.
+    ///
+    /// Storages are borrowed only when the entity is built and missing ones are registered.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize, u32)>();
+    /// world.tight_pack::<(usize, u32)>();
+    ///
+    /// let entity = world.entity_builder().with(0usize).with(1u32).build();
+    ///
+    /// world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+    ///     assert_eq!((&usizes, &u32s).get(entity), Some((&0, &1)));
+    /// });
+    /// ```
+    pub fn entity_builder(&self) -> EntityBuilder<'_, ()> {
+        EntityBuilder::new(self)
+    }
+    /// Register an event channel for `T`.
+    /// Does nothing if the channel already exists.
+    ///
+    /// Events are sent and read through [Events].
+    ///
+    /// Unwraps errors.
+    ///
+    /// [Events]: struct.Events.html
+    pub fn register_events<T: 'static + Send + Sync>(&self) {
+        self.try_register_events::<T>().unwrap();
+    }
+    /// Register an event channel for `T`.
+    /// Does nothing if the channel already exists.
+    ///
+    /// Events are sent and read through [Events].
+    ///
+    /// [Events]: struct.Events.html
+    pub fn try_register_events<T: 'static + Send + Sync>(&self) -> Result<(), error::Borrow> {
+        self.try_register_unique(EventQueue::<T>::default())
+    }
     /// Allows to perform some actions not possible otherwise like iteration.
     /// This is basically an unnamed system.
     ///
@@ -139,6 +201,10 @@ impl World {
     /// * [AllStorages] for a mutable reference to the storage of all components
     /// * [ThreadPool] for an immutable reference to the `rayon::ThreadPool` used by the [World]
     /// * [Not] can be used to filter out a component type
+    /// * [Commands] to record entity and component changes applied later
+    /// * [Events] to send or read events
+    /// * [DynamicStorages] for the storages of components registered at runtime, without running in parallel with other systems using them
+    /// * [SharedDynamicStorages] for the same storages, running in parallel with other systems using them
     ///
     /// A tuple will allow multiple references.
     ///
@@ -156,6 +222,10 @@ impl World {
     /// [ThreadPool]: struct.ThreadPool.html
     /// [World]: struct.World.html
     /// [Not]: struct.Not.html
+    /// [Commands]: struct.Commands.html
+    /// [Events]: struct.Events.html
+    /// [DynamicStorages]: struct.DynamicStorages.html
+    /// [SharedDynamicStorages]: struct.SharedDynamicStorages.html
     pub fn run<'a, T: Run<'a>, R: 'static, F: FnOnce(T::Storage) -> R>(&'a self, f: F) -> R {
         self.try_run::<T, _, _>(f).unwrap()
     }
@@ -170,6 +240,10 @@ impl World {
     /// * [AllStorages] for a mutable reference to the storage of all components
     /// * [ThreadPool] for an immutable reference to the `rayon::ThreadPool` used by the [World]
     /// * [Not] can be used to filter out a component type
+    /// * [Commands] to record entity and component changes applied later
+    /// * [Events] to send or read events
+    /// * [DynamicStorages] for the storages of components registered at runtime, without running in parallel with other systems using them
+    /// * [SharedDynamicStorages] for the same storages, running in parallel with other systems using them
     ///
     /// A tuple will allow multiple references.
     /// # Example
@@ -185,19 +259,57 @@ impl World {
     /// [ThreadPool]: struct.ThreadPool.html
     /// [World]: struct.World.html
     /// [Not]: struct.Not.html
+    /// [Commands]: struct.Commands.html
+    /// [Events]: struct.Events.html
+    /// [DynamicStorages]: struct.DynamicStorages.html
+    /// [SharedDynamicStorages]: struct.SharedDynamicStorages.html
     pub fn try_run<'a, T: Run<'a>, R: 'static, F: FnOnce(T::Storage) -> R>(
         &'a self,
         f: F,
+    ) -> Result<R, error::GetStorage> {
+        let ticks = SystemTicks {
+            last_run: 0,
+            current: self.next_tick(),
+            oldest: self.oldest_run.load(Ordering::Relaxed),
+            system_id: None,
+        };
+        self.try_run_with_ticks::<T, _, _>(ticks, f)
+    }
+    /// Same as `try_run` but changes are compared against and stamped with `ticks`.
+    pub(crate) fn try_run_with_ticks<'a, T: Run<'a>, R: 'static, F: FnOnce(T::Storage) -> R>(
+        &'a self,
+        ticks: SystemTicks,
+        f: F,
     ) -> Result<R, error::GetStorage> {
         #[cfg(feature = "parallel")]
         {
-            T::try_run(&self.storages, &self.thread_pool, f)
+            T::try_run(&self.storages, ticks, &self.thread_pool, f)
         }
         #[cfg(not(feature = "parallel"))]
         {
-            T::try_run(&self.storages, f)
+            T::try_run(&self.storages, ticks, f)
         }
     }
+    /// Returns a new tick, greater than all previous ones.
+    pub(crate) fn next_tick(&self) -> u32 {
+        self.tick.fetch_add(1, Ordering::Relaxed).wrapping_add(1)
+    }
+    /// Returns the ticks of workload system `system_id` that last ran at `last_run` and updates it to the new tick.
+    pub(crate) fn system_ticks(&self, last_run: &AtomicU32, system_id: usize) -> SystemTicks {
+        let current = self.next_tick();
+        SystemTicks {
+            last_run: last_run.swap(current, Ordering::Relaxed),
+            current,
+            oldest: self.oldest_run.load(Ordering::Relaxed),
+            system_id: Some(system_id),
+        }
+    }
+    /// Updates the last run of the workload system which ran the longest time ago.
+    fn update_oldest_run(&self, pipeline: &Pipeline) {
+        let current = self.tick.load(Ordering::Relaxed);
+        self.oldest_run
+            .store(pipeline.oldest_run(current), Ordering::Relaxed);
+    }
     /// Pack multiple storages together, it can speed up iteration at a small cost on insertion/removal.
     /// # Example
     /// ```
@@ -232,6 +344,12 @@ impl World {
     {
         <(T, L)>::try_loose_pack(&self.storages).unwrap()
     }
+    /// Update packs keep track of inserted and modified components of type `T`.
+    ///
+    /// `inserted`, `modified` and `take_removed` share one window between all systems,
+    /// the first system to clear it steals the events from the others.\
+    /// Update packs also track changes, each system can use `added`, `changed` and `removed`
+    /// on its view to get what happened since it last ran, independently of other systems.
     pub fn try_update_pack<T: 'static>(&self) -> Result<(), error::Pack> {
         use std::any::{type_name, TypeId};
 
@@ -253,6 +371,7 @@ impl World {
                         modified: 0,
                         removed: Vec::new(),
                     });
+                    sparse_set.track_changes();
                     Ok(())
                 }
                 Pack::Tight(_) => Err(error::Pack::AlreadyTightPack(TypeId::of::<T>())),
@@ -263,9 +382,191 @@ impl World {
             Err(error::GetStorage::MissingComponent(type_name::<T>()).into())
         }
     }
+    /// Update packs keep track of inserted and modified components of type `T`.
+    ///
+    /// `inserted`, `modified` and `take_removed` share one window between all systems,
+    /// the first system to clear it steals the events from the others.\
+    /// Update packs also track changes, each system can use `added`, `changed` and `removed`
+    /// on its view to get what happened since it last ran, independently of other systems.
+    ///
+    /// Unwraps errors.
     pub fn update_pack<T: 'static>(&self) {
         self.try_update_pack::<T>().unwrap();
     }
+    /// Starts tracking when `T` components are added and modified.
+    ///
+    /// Systems can then use `is_added`, `is_changed`, `added`, `changed` and `removed` on their views
+    /// to know what happened since they last ran, no need to clear anything.\
+    /// Removals are kept until all systems of the World's workloads ran after them.\
+    /// Without workloads or when a workload system never runs, removals accumulate until `ViewMut::clear_removed` is called.\
+    /// Unlike update packs, it works with tight and loose packs.\
+    /// Components already present are not considered added.\
+    /// A component is considered modified as soon as it's accessed mutably, by `get` or iteration.\
+    /// Outside of workloads, `World::run` sees all changes made since tracking started.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize,)>();
+    /// world.track_changes::<usize>();
+    ///
+    /// world.add_workload(
+    ///     "Check",
+    ///     WorkloadSystem::from_fn::<&usize, _>(|usizes| {
+    ///         assert_eq!(usizes.added().count(), 1);
+    ///     }),
+    /// );
+    ///
+    /// world.run::<(EntitiesMut, &mut usize), _, _>(|(mut entities, mut usizes)| {
+    ///     entities.add_entity((&mut usizes,), (0,));
+    /// });
+    /// world.run_default();
+    /// ```
+    pub fn try_track_changes<T: 'static>(&self) -> Result<(), error::GetStorage> {
+        use std::any::type_name;
+
+        let all_storages = self
+            .storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?;
+        if let Some(storage) = all_storages.0.get(&std::any::TypeId::of::<T>()) {
+            storage
+                .sparse_set_mut::<T>()
+                .map_err(|err| error::GetStorage::StorageBorrow((type_name::<T>(), err)))?
+                .track_changes();
+            Ok(())
+        } else {
+            Err(error::GetStorage::MissingComponent(type_name::<T>()))
+        }
+    }
+    /// Starts tracking when `T` components are added and modified.
+    ///
+    /// Systems can then use `is_added`, `is_changed`, `added`, `changed` and `removed` on their views
+    /// to know what happened since they last ran, no need to clear anything.\
+    /// Removals are kept until all systems of the World's workloads ran after them.\
+    /// Unlike update packs, it works with tight and loose packs.\
+    /// Components already present are not considered added.
+    ///
+    /// Unwraps errors.
+    pub fn track_changes<T: 'static>(&self) {
+        self.try_track_changes::<T>().unwrap();
+    }
+    /// Replaces the callbacks run when `T` components are inserted, removed or deleted.
+    ///
+    /// See [Hooks] for when each callback runs.
+    ///
+    /// [Hooks]: struct.Hooks.html
+    pub fn try_set_hooks<T: 'static>(&self, hooks: Hooks<T>) -> Result<(), error::GetStorage> {
+        use std::any::type_name;
+
+        let all_storages = self
+            .storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?;
+        if let Some(storage) = all_storages.0.get(&std::any::TypeId::of::<T>()) {
+            storage
+                .sparse_set_mut::<T>()
+                .map_err(|err| error::GetStorage::StorageBorrow((type_name::<T>(), err)))?
+                .set_hooks(hooks);
+            Ok(())
+        } else {
+            Err(error::GetStorage::MissingComponent(type_name::<T>()))
+        }
+    }
+    /// Replaces the callbacks run when `T` components are inserted, removed or deleted.
+    ///
+    /// See [Hooks] for when each callback runs.
+    ///
+    /// Unwraps errors.
+    ///
+    /// [Hooks]: struct.Hooks.html
+    pub fn set_hooks<T: 'static>(&self, hooks: Hooks<T>) {
+        self.try_set_hooks(hooks).unwrap();
+    }
+    /// Lets entities whose version reached its maximum be recycled, their version starts back at 0.
+    ///
+    /// By default these entities are never recycled, their index is lost.\
+    /// With wraparound, an old `EntityId` could match a new entity if its index was reused
+    /// as many times as there are versions in between.
+    /// Entities which died before this call stay dead.
+    pub fn try_enable_version_wraparound(&self) -> Result<(), error::GetStorage> {
+        self.storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .0[&std::any::TypeId::of::<Entities>()]
+            .entities_mut()
+            .map_err(error::GetStorage::Entities)?
+            .enable_version_wraparound();
+        Ok(())
+    }
+    /// Lets entities whose version reached its maximum be recycled, their version starts back at 0.
+    ///
+    /// By default these entities are never recycled, their index is lost.\
+    /// With wraparound, an old `EntityId` could match a new entity if its index was reused
+    /// as many times as there are versions in between.
+    /// Entities which died before this call stay dead.
+    ///
+    /// Unwraps errors.
+    pub fn enable_version_wraparound(&self) {
+        self.try_enable_version_wraparound().unwrap();
+    }
+    /// Creates a storage for a component type only known at runtime and returns its id.
+    ///
+    /// Registering the same name twice creates two different storages,
+    /// `DynamicStoragesView::id` returns the first one.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// use std::alloc::Layout;
+    ///
+    /// let world = World::default();
+    /// let health = world.register_dynamic(DynamicComponent::new("Health", Layout::new::<u32>()));
+    ///
+    /// let entity = world.entity_builder().build();
+    ///
+    /// world.run::<(Entities, DynamicStorages), _, _>(|(entities, storages)| {
+    ///     let mut healths = storages.view_mut(health);
+    ///     healths.add_component(&100u32.to_ne_bytes(), entity, &entities);
+    ///     assert_eq!(healths.get(entity), Some(&100u32.to_ne_bytes()[..]));
+    /// });
+    /// ```
+    pub fn try_register_dynamic(
+        &self,
+        component: DynamicComponent,
+    ) -> Result<DynamicId, error::GetStorage> {
+        Ok(self
+            .storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .0[&std::any::TypeId::of::<DynamicStorages>()]
+            .dynamic_storages_mut()
+            .map_err(|err| error::GetStorage::StorageBorrow(("DynamicStorages", err)))?
+            .register(component))
+    }
+    /// Creates a storage for a component type only known at runtime and returns its id.
+    ///
+    /// Registering the same name twice creates two different storages,
+    /// `DynamicStoragesView::id` returns the first one.
+    ///
+    /// Unwraps errors.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// use std::alloc::Layout;
+    ///
+    /// let world = World::default();
+    /// let health = world.register_dynamic(DynamicComponent::new("Health", Layout::new::<u32>()));
+    ///
+    /// let entity = world.entity_builder().build();
+    ///
+    /// world.run::<(Entities, DynamicStorages), _, _>(|(entities, storages)| {
+    ///     let mut healths = storages.view_mut(health);
+    ///     healths.add_component(&100u32.to_ne_bytes(), entity, &entities);
+    ///     assert_eq!(healths.get(entity), Some(&100u32.to_ne_bytes()[..]));
+    /// });
+    /// ```
+    pub fn register_dynamic(&self, component: DynamicComponent) -> DynamicId {
+        self.try_register_dynamic(component).unwrap()
+    }
     /// Modifies the current default workload to `name`.
     pub fn try_set_default_workload(
         &self,
@@ -290,6 +591,8 @@ impl World {
     /// They will execute as much in parallel as possible.
     ///
     /// They are evaluated left to right when they can't be parallelized.
+    /// Systems can be explicitly ordered with [SystemOrdering].
+    /// Workloads with more than 10 systems or closures can be made with [WorkloadBuilder].
     ///
     /// The default workload will automatically be set to the first workload added.
     /// # Example
@@ -327,19 +630,25 @@ impl World {
     /// world.try_add_workload("Add & Check", (Adder, Checker)).unwrap();
     /// world.run_default();
     /// ```
+    ///
+    /// [SystemOrdering]: trait.SystemOrdering.html
+    /// [WorkloadBuilder]: struct.WorkloadBuilder.html
     pub fn try_add_workload<T: Workload>(
         &self,
         name: impl ToString,
         system: T,
-    ) -> Result<(), error::Borrow> {
+    ) -> Result<(), error::AddWorkload> {
         let mut pipeline = self.pipeline.try_borrow_mut()?;
-        system.into_workload(name.to_string(), &mut *pipeline);
+        system.into_workload(name.to_string(), &mut *pipeline)?;
+        self.update_oldest_run(&pipeline);
         Ok(())
     }
     /// A workload is a collection of systems.
     /// They will execute as much in parallel as possible.
     ///
     /// They are evaluated left to right when they can't be parallelized.
+    /// Systems can be explicitly ordered with [SystemOrdering].
+    /// Workloads with more than 10 systems or closures can be made with [WorkloadBuilder].
     ///
     /// The default workload will automatically be set to the first workload added.
     ///
@@ -379,32 +688,20 @@ impl World {
     /// world.add_workload("Add & Check", (Adder, Checker));
     /// world.run_default();
     /// ```
+    ///
+    /// [SystemOrdering]: trait.SystemOrdering.html
+    /// [WorkloadBuilder]: struct.WorkloadBuilder.html
     pub fn add_workload<T: Workload>(&self, name: impl ToString, system: T) {
         self.try_add_workload(name, system).unwrap();
     }
     /// Runs the `name` workload.
+    ///
+    /// If a system fails, the other systems of its batch still run but the following batches don't.
+    /// The error of the first failing system of the batch is returned.
     pub fn try_run_workload(&self, name: impl AsRef<str>) -> Result<(), error::RunWorkload> {
         let pipeline = self.pipeline.try_borrow()?;
         if let Some(workload) = pipeline.workloads.get(name.as_ref()) {
-            for batch in &pipeline.batch[workload.clone()] {
-                #[cfg(feature = "parallel")]
-                {
-                    use rayon::prelude::*;
-
-                    self.thread_pool.install(|| {
-                        batch.into_par_iter().for_each(|&index| {
-                            (pipeline.systems[index])(&self);
-                        });
-                    })
-                }
-                #[cfg(not(feature = "parallel"))]
-                {
-                    batch.iter().for_each(|&index| {
-                        (pipeline.systems[index])(&self);
-                    });
-                }
-            }
-            Ok(())
+            self.run_batches(&pipeline, workload.clone())
         } else {
             Err(error::RunWorkload::MissingWorkload)
         }
@@ -415,33 +712,184 @@ impl World {
     pub fn run_workload(&self, name: impl AsRef<str>) {
         self.try_run_workload(name).unwrap();
     }
+    /// Returns the batches computed for the `name` workload.
+    ///
+    /// For each system it lists its borrows and why it couldn't run in the previous batch.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// # use shipyard::Conflict;
+    /// struct Writer;
+    /// impl<'a> System<'a> for Writer {
+    ///     type Data = &'a mut usize;
+    ///     fn run(_: <Self::Data as SystemData>::View) {}
+    /// }
+    ///
+    /// struct Reader;
+    /// impl<'a> System<'a> for Reader {
+    ///     type Data = &'a usize;
+    ///     fn run(_: <Self::Data as SystemData>::View) {}
+    /// }
+    ///
+    /// let world = World::new::<(usize,)>();
+    /// world.add_workload("Frame", (Writer, Reader));
+    ///
+    /// let info = world.workload_info("Frame");
+    /// assert_eq!(info.batches.len(), 2);
+    /// assert!(info.batches[1].systems[0].name.ends_with("Reader"));
+    /// match info.batches[1].systems[0].conflict {
+    ///     Some(Conflict::Borrow { other_system, .. }) => assert!(other_system.ends_with("Writer")),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    pub fn try_workload_info(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<WorkloadInfo, error::RunWorkload> {
+        let pipeline = self.pipeline.try_borrow()?;
+        if let Some(workload) = pipeline.workloads.get(name.as_ref()) {
+            Ok(WorkloadInfo {
+                name: name.as_ref().to_string(),
+                batches: pipeline.batch[workload.clone()]
+                    .iter()
+                    .map(|batch| BatchInfo {
+                        systems: batch
+                            .iter()
+                            .map(|&index| pipeline.system_infos[index].clone())
+                            .collect(),
+                    })
+                    .collect(),
+            })
+        } else {
+            Err(error::RunWorkload::MissingWorkload)
+        }
+    }
+    /// Returns the batches computed for the `name` workload.
+    ///
+    /// For each system it lists its borrows and why it couldn't run in the previous batch.
+    ///
+    /// Unwraps errors.
+    pub fn workload_info(&self, name: impl AsRef<str>) -> WorkloadInfo {
+        self.try_workload_info(name).unwrap()
+    }
     /// Run the default workload.
-    pub fn try_run_default(&self) -> Result<(), error::Borrow> {
+    ///
+    /// If a system fails, the other systems of its batch still run but the following batches don't.
+    /// The error of the first failing system of the batch is returned.
+    pub fn try_run_default(&self) -> Result<(), error::RunWorkload> {
         let pipeline = self.pipeline.try_borrow()?;
-        for batch in &pipeline.batch[pipeline.default.clone()] {
+        self.run_batches(&pipeline, pipeline.default.clone())
+    }
+    /// Run the default workload.
+    ///
+    /// Unwraps error.
+    pub fn run_default(&self) {
+        self.try_run_default().unwrap();
+    }
+    /// Runs `batches` one after the other and applies commands after each of them.
+    fn run_batches(
+        &self,
+        pipeline: &Pipeline,
+        batches: Range<usize>,
+    ) -> Result<(), error::RunWorkload> {
+        let mut result = Ok(());
+
+        for batch in &pipeline.batch[batches] {
             #[cfg(feature = "parallel")]
-            {
+            let error = {
                 use rayon::prelude::*;
 
                 self.thread_pool.install(|| {
-                    batch.into_par_iter().for_each(|&index| {
-                        (pipeline.systems[index])(&self);
-                    });
+                    batch
+                        .into_par_iter()
+                        .filter_map(|&index| {
+                            (pipeline.systems[index])(&self)
+                                .err()
+                                .map(|error| (index, error))
+                        })
+                        .min_by_key(|(index, _)| *index)
                 })
-            }
+            };
             #[cfg(not(feature = "parallel"))]
-            {
-                batch.iter().for_each(|&index| {
-                    (pipeline.systems[index])(&self);
+            let error = {
+                let mut errors = batch.iter().filter_map(|&index| {
+                    (pipeline.systems[index])(&self)
+                        .err()
+                        .map(|error| (index, error))
                 });
+                let error = errors.next();
+                // the rest of the batch still runs, like with the parallel feature
+                errors.for_each(drop);
+                error
+            };
+
+            let commands = self.try_apply_commands();
+
+            if let Some((index, error)) = error {
+                result = Err(error::RunWorkload::System((
+                    pipeline.system_infos[index].name,
+                    error.into(),
+                )));
+                break;
+            }
+            if let Err(error) = commands {
+                result = Err(error::RunWorkload::ApplyCommands(error));
+                break;
             }
         }
-        Ok(())
+
+        self.update_oldest_run(pipeline);
+
+        result
     }
-    /// Run the default workload.
+    /// Applies all commands recorded since the last sync point.
     ///
-    /// Unwraps error.
-    pub fn run_default(&self) {
-        self.try_run_default().unwrap();
+    /// Workloads apply commands automatically after each batch of systems,
+    /// this is only needed for commands recorded with [run].\
+    /// Entities reserved with `EntitiesView::reserve_entity` are added to the entities storage first.
+    ///
+    /// All commands are applied even if one of them fails, the first error is returned.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize,)>();
+    ///
+    /// world.run::<Commands, _, _>(|mut commands| {
+    ///     commands.add_entity((0usize,));
+    /// });
+    ///
+    /// world.try_apply_commands().unwrap();
+    ///
+    /// world.run::<&usize, _, _>(|usizes| {
+    ///     assert_eq!(usizes.len(), 1);
+    /// });
+    /// ```
+    /// [run]: struct.World.html#method.run
+    pub fn try_apply_commands(&self) -> Result<(), error::ApplyCommands> {
+        self.storages
+            .try_borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .0[&std::any::TypeId::of::<Entities>()]
+            .entities_mut()
+            .map_err(error::GetStorage::Entities)?
+            .flush_reserved();
+
+        let commands = self.try_run::<Unique<&CommandQueue>, _, _>(|queue| queue.take())?;
+        commands
+            .into_iter()
+            .map(|command| command(self))
+            .fold(Ok(()), Result::and)
+    }
+    /// Applies all commands recorded since the last sync point.
+    ///
+    /// Workloads apply commands automatically after each batch of systems,
+    /// this is only needed for commands recorded with [run].\
+    /// Entities reserved with `EntitiesView::reserve_entity` are added to the entities storage first.
+    ///
+    /// Unwraps errors.
+    ///
+    /// [run]: struct.World.html#method.run
+    pub fn apply_commands(&self) {
+        self.try_apply_commands().unwrap();
     }
 }
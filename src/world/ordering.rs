@@ -0,0 +1,145 @@
+use super::pipeline::{IntoWorkloadSystem, WorkloadSystem};
+use crate::run::System;
+use std::any::{type_name, TypeId};
+
+/// What an ordering constraint refers to.
+#[derive(Clone, Copy)]
+pub(crate) enum Target {
+    System(TypeId, &'static str),
+    Label(&'static str),
+}
+
+/// A system with ordering constraints.
+///
+/// Made with [SystemOrdering]'s methods.
+///
+/// [SystemOrdering]: trait.SystemOrdering.html
+pub struct Ordered<S> {
+    system: S,
+    labels: Vec<&'static str>,
+    before: Vec<Target>,
+    after: Vec<Target>,
+}
+
+/// Adds ordering constraints to systems inside a workload.
+///
+/// By default systems are only ordered by their position in the workload and their borrows.
+/// These constraints are respected even when the systems could run in parallel.
+///
+/// Constraints only apply to systems of the same workload.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// struct Spawn;
+/// impl<'a> System<'a> for Spawn {
+///     type Data = &'a usize;
+///     fn run(_: <Self::Data as SystemData>::View) {}
+/// }
+///
+/// struct Move;
+/// impl<'a> System<'a> for Move {
+///     type Data = &'a u32;
+///     fn run(_: <Self::Data as SystemData>::View) {}
+/// }
+///
+/// struct Render;
+/// impl<'a> System<'a> for Render {
+///     type Data = &'a u64;
+///     fn run(_: <Self::Data as SystemData>::View) {}
+/// }
+///
+/// let world = World::new::<(usize, u32, u64)>();
+///
+/// world.add_workload(
+///     "Frame",
+///     (
+///         Render.after_label("logic"),
+///         Move.label("logic").after::<Spawn>(),
+///         Spawn.label("logic"),
+///     ),
+/// );
+/// world.run_default();
+/// ```
+pub trait SystemOrdering: Sized {
+    type System;
+
+    fn into_ordered(self) -> Ordered<Self::System>;
+    /// Adds `label` to this system, other systems can then be ordered relatively to all systems with this label.
+    fn label(self, label: &'static str) -> Ordered<Self::System> {
+        let mut ordered = self.into_ordered();
+        ordered.labels.push(label);
+        ordered
+    }
+    /// This system will run before `S`.
+    fn before<S: 'static>(self) -> Ordered<Self::System> {
+        let mut ordered = self.into_ordered();
+        ordered
+            .before
+            .push(Target::System(TypeId::of::<S>(), type_name::<S>()));
+        ordered
+    }
+    /// This system will run after `S`.
+    fn after<S: 'static>(self) -> Ordered<Self::System> {
+        let mut ordered = self.into_ordered();
+        ordered
+            .after
+            .push(Target::System(TypeId::of::<S>(), type_name::<S>()));
+        ordered
+    }
+    /// This system will run before all systems with `label`.
+    fn before_label(self, label: &'static str) -> Ordered<Self::System> {
+        let mut ordered = self.into_ordered();
+        ordered.before.push(Target::Label(label));
+        ordered
+    }
+    /// This system will run after all systems with `label`.
+    fn after_label(self, label: &'static str) -> Ordered<Self::System> {
+        let mut ordered = self.into_ordered();
+        ordered.after.push(Target::Label(label));
+        ordered
+    }
+}
+
+impl<T: for<'a> System<'a> + Send + Sync + 'static> SystemOrdering for T {
+    type System = T;
+
+    fn into_ordered(self) -> Ordered<Self::System> {
+        Ordered {
+            system: self,
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+}
+
+impl SystemOrdering for WorkloadSystem {
+    type System = WorkloadSystem;
+
+    fn into_ordered(self) -> Ordered<Self::System> {
+        Ordered {
+            system: self,
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+}
+
+impl<S> SystemOrdering for Ordered<S> {
+    type System = S;
+
+    fn into_ordered(self) -> Ordered<Self::System> {
+        self
+    }
+}
+
+impl<S: IntoWorkloadSystem> IntoWorkloadSystem for Ordered<S> {
+    fn into_workload_system(self) -> WorkloadSystem {
+        let mut system = self.system.into_workload_system();
+        system.labels.extend(self.labels);
+        system.before.extend(self.before);
+        system.after.extend(self.after);
+        system
+    }
+}
@@ -1,152 +1,474 @@
-use crate::run::{Dispatch, Mutation, System, SystemData};
+use super::ordering::Target;
+use super::workload_info::{Conflict, SystemInfo};
+use crate::error;
+use crate::run::{Dispatch, Mutation, System, SystemData, TryDispatch, TrySystem};
 use crate::storage::{AllStorages, Entities};
 use crate::world::World;
-use std::any::TypeId;
+use std::any::{type_name, TypeId};
 use std::collections::HashMap;
+use std::error::Error;
 use std::ops::Range;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Arc;
 
 #[allow(clippy::type_complexity)]
 pub struct Pipeline {
-    pub(super) systems: Vec<Box<dyn for<'a> Fn(&'a World) + Send + Sync>>,
+    pub(super) systems: Vec<
+        Box<dyn for<'a> Fn(&'a World) -> Result<(), Box<dyn Error + Send + Sync>> + Send + Sync>,
+    >,
+    // name, borrows and scheduling of each system
+    pub(super) system_infos: Vec<SystemInfo>,
     // a batch list systems running in parallel
     pub(super) batch: Vec<Box<[usize]>>,
     // first usize is the index where the workload begins
     // the second is the number of batch in it
     pub(super) workloads: HashMap<String, Range<usize>>,
     pub(super) default: Range<usize>,
+    // tick at which each system last ran
+    pub(super) last_runs: Vec<Arc<AtomicU32>>,
 }
 
 impl Default for Pipeline {
     fn default() -> Self {
         Pipeline {
             systems: Vec::new(),
+            system_infos: Vec::new(),
             batch: Vec::new(),
             workloads: HashMap::new(),
             default: 0..0,
+            last_runs: Vec::new(),
         }
     }
 }
 
-pub trait Workload {
-    fn into_workload(self, name: String, pipeline: &mut Pipeline);
+/// A type erased system ready to be added to a workload.
+///
+/// Can be made from a [System] or a closure, and ordered with [SystemOrdering].
+///
+/// [System]: trait.System.html
+/// [SystemOrdering]: trait.SystemOrdering.html
+#[allow(clippy::type_complexity)]
+pub struct WorkloadSystem {
+    pub(super) system:
+        Box<dyn for<'a> Fn(&'a World) -> Result<(), Box<dyn Error + Send + Sync>> + Send + Sync>,
+    // tick at which the system last ran, shared with `system`
+    pub(super) last_run: Arc<AtomicU32>,
+    pub(super) borrow_infos: Vec<(TypeId, Mutation)>,
+    pub(super) type_id: TypeId,
+    pub(super) name: &'static str,
+    pub(super) labels: Vec<&'static str>,
+    pub(super) before: Vec<Target>,
+    pub(super) after: Vec<Target>,
 }
 
-impl<T: for<'a> System<'a> + Send + Sync + 'static> Workload for T {
-    #[allow(clippy::range_plus_one)]
-    fn into_workload(self, name: String, pipeline: &mut Pipeline) {
-        if pipeline.workloads.is_empty() {
-            pipeline.default = pipeline.batch.len()..(pipeline.batch.len() + 1);
+/// Returns an id no other workload system has, event readers keep their cursor under it.
+fn next_system_id() -> usize {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+pub trait IntoWorkloadSystem {
+    fn into_workload_system(self) -> WorkloadSystem;
+}
+
+impl WorkloadSystem {
+    /// Type erases `system`.
+    pub fn new<S: for<'a> System<'a> + Send + Sync + 'static>(system: S) -> Self {
+        system.into_workload_system()
+    }
+    /// Type erases a fallible `system`.
+    pub fn try_new<S: for<'a> TrySystem<'a> + Send + Sync + 'static>(_system: S) -> Self {
+        let mut borrow_infos = Vec::new();
+        <S::Data as SystemData>::borrow_infos(&mut borrow_infos);
+
+        let last_run = Arc::new(AtomicU32::new(0));
+        let system_last_run = last_run.clone();
+        let system_id = next_system_id();
+
+        WorkloadSystem {
+            system: Box::new(move |world| {
+                S::try_dispatch_fallible(world, world.system_ticks(&system_last_run, system_id))
+            }),
+            last_run,
+            borrow_infos,
+            type_id: TypeId::of::<S>(),
+            name: type_name::<S>(),
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+    /// Makes a system out of a closure, `T` is what the closure borrows just like [System::Data].
+    ///
+    /// Closures can't be targeted by [SystemOrdering::before] and [SystemOrdering::after], use labels instead.
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize, u32)>();
+    ///
+    /// world.add_workload(
+    ///     "Closure",
+    ///     WorkloadSystem::from_fn::<(&mut usize, &u32), _>(|(usizes, u32s)| {
+    ///         (usizes, u32s).iter().for_each(|(x, &y)| *x += y as usize);
+    ///     }),
+    /// );
+    /// world.run_default();
+    /// ```
+    /// [System::Data]: trait.System.html#associatedtype.Data
+    /// [SystemOrdering::before]: trait.SystemOrdering.html#method.before
+    /// [SystemOrdering::after]: trait.SystemOrdering.html#method.after
+    pub fn from_fn<T, F>(f: F) -> Self
+    where
+        T: for<'a> SystemData<'a>,
+        F: for<'a> Fn(<T as SystemData<'a>>::View) + Send + Sync + 'static,
+    {
+        let mut borrow_infos = Vec::new();
+        T::borrow_infos(&mut borrow_infos);
+
+        let last_run = Arc::new(AtomicU32::new(0));
+        let system_last_run = last_run.clone();
+        let system_id = next_system_id();
+
+        WorkloadSystem {
+            system: Box::new(move |world| {
+                world.try_run_with_ticks::<T, _, _>(
+                    world.system_ticks(&system_last_run, system_id),
+                    &f,
+                )?;
+                Ok(())
+            }),
+            last_run,
+            borrow_infos,
+            type_id: TypeId::of::<F>(),
+            name: type_name::<F>(),
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+    /// Makes a fallible system out of a closure, `T` is what the closure borrows just like [System::Data].
+    ///
+    /// Closures can't be targeted by [SystemOrdering::before] and [SystemOrdering::after], use labels instead.
+    ///
+    /// [System::Data]: trait.System.html#associatedtype.Data
+    /// [SystemOrdering::before]: trait.SystemOrdering.html#method.before
+    /// [SystemOrdering::after]: trait.SystemOrdering.html#method.after
+    pub fn from_try_fn<T, E, F>(f: F) -> Self
+    where
+        T: for<'a> SystemData<'a>,
+        E: Error + Send + Sync + 'static,
+        F: for<'a> Fn(<T as SystemData<'a>>::View) -> Result<(), E> + Send + Sync + 'static,
+    {
+        let mut borrow_infos = Vec::new();
+        T::borrow_infos(&mut borrow_infos);
+
+        let last_run = Arc::new(AtomicU32::new(0));
+        let system_last_run = last_run.clone();
+        let system_id = next_system_id();
+
+        WorkloadSystem {
+            system: Box::new(move |world| {
+                world.try_run_with_ticks::<T, _, _>(
+                    world.system_ticks(&system_last_run, system_id),
+                    &f,
+                )??;
+                Ok(())
+            }),
+            last_run,
+            borrow_infos,
+            type_id: TypeId::of::<F>(),
+            name: type_name::<F>(),
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
         }
-        pipeline
-            .workloads
-            .insert(name, pipeline.batch.len()..(pipeline.batch.len() + 1));
-        pipeline.batch.push(Box::new([pipeline.systems.len()]));
-        pipeline
-            .systems
-            .push(Box::new(|world| T::try_dispatch(world).unwrap()));
     }
 }
 
-impl<T: for<'a> System<'a> + Send + Sync + 'static> Workload for (T,) {
-    fn into_workload(self, name: String, pipeline: &mut Pipeline) {
-        self.0.into_workload(name, pipeline)
+impl IntoWorkloadSystem for WorkloadSystem {
+    fn into_workload_system(self) -> WorkloadSystem {
+        self
     }
 }
 
-macro_rules! impl_pipeline {
-    ($(($type: ident, $index: tt))+) => {
-        impl<$($type: for<'a> System<'a> + Send + Sync + 'static),+> Workload for ($($type,)+) {
-            fn into_workload(self, name: String, pipeline: &mut Pipeline) {
-                let batch_start = pipeline.batch.len();
-                let mut new_batch: Vec<Vec<usize>> = vec![Vec::new()];
-                let mut batch_info: Vec<Vec<(TypeId, Mutation)>> = vec![Vec::new()];
-
-
-                $({
-                    let mut borrow_infos = Vec::new();
-                    $type::Data::borrow_infos(&mut borrow_infos);
-                    let mut batch_index = batch_info.len();
-                    for batch in batch_info.iter().rev() {
-                        let mut conflict = false;
-
-                        for &(type_id, mutation) in &borrow_infos {
-                            match mutation {
-                                Mutation::Shared => {
-                                    for &(batch_type_id, mutation) in batch.iter() {
-                                        #[cfg(feature = "parallel")]
-                                        {
-                                            if type_id == batch_type_id && mutation == Mutation::Unique
-                                            || (batch_type_id == TypeId::of::<AllStorages>() && type_id != TypeId::of::<crate::ThreadPool>()) {
-                                                conflict = true;
-                                                break;
-                                            }
-                                        }
-                                        #[cfg(not(feature = "parallel"))]
-                                        {
-                                            if type_id == batch_type_id && mutation == Mutation::Unique
-                                            || batch_type_id == TypeId::of::<AllStorages>() {
-                                                conflict = true;
-                                                break;
-                                            }
-                                        }
-                                    };
-
-
-                                },
-                                Mutation::Unique => {
-                                    for &(batch_type_id, _) in batch.iter() {
-                                        #[cfg(feature = "parallel")]
-                                        {
-                                            if type_id == batch_type_id
-                                                || (type_id == TypeId::of::<AllStorages>()
-                                                    && (batch_type_id != TypeId::of::<crate::ThreadPool>()
-                                                    && batch_type_id != TypeId::of::<Entities>()))
-                                            {
-                                                conflict = true;
-                                                break;
-                                            }
-                                        }
-                                        #[cfg(not(feature = "parallel"))]
-                                        {
-                                            if type_id == batch_type_id
-                                                || (type_id == TypeId::of::<AllStorages>()
-                                                    && batch_type_id != TypeId::of::<Entities>())
-                                            {
-                                                conflict = true;
-                                                break;
-                                            }
-                                        }
-                                    };
-                                },
-                            }
+impl<T: for<'a> System<'a> + Send + Sync + 'static> IntoWorkloadSystem for T {
+    fn into_workload_system(self) -> WorkloadSystem {
+        let mut borrow_infos = Vec::new();
+        <T::Data as SystemData>::borrow_infos(&mut borrow_infos);
+
+        let last_run = Arc::new(AtomicU32::new(0));
+        let system_last_run = last_run.clone();
+        let system_id = next_system_id();
+
+        WorkloadSystem {
+            system: Box::new(move |world| {
+                T::try_dispatch(world, world.system_ticks(&system_last_run, system_id))?;
+                Ok(())
+            }),
+            last_run,
+            borrow_infos,
+            type_id: TypeId::of::<T>(),
+            name: type_name::<T>(),
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+}
+
+impl Pipeline {
+    /// Returns the last run of the system which ran the longest time ago, relative to `current`.\
+    /// Without systems `current` is returned.
+    pub(super) fn oldest_run(&self, current: u32) -> u32 {
+        self.last_runs
+            .iter()
+            .map(|last_run| last_run.load(Ordering::Relaxed))
+            .max_by_key(|&last_run| current.wrapping_sub(last_run))
+            .unwrap_or(current)
+    }
+    /// Computes the batches of `systems` and adds them as the `name` workload.
+    ///
+    /// Systems are placed in the first batch following all batches
+    /// they conflict with or they have to run after.
+    pub(super) fn add_workload(
+        &mut self,
+        name: String,
+        systems: Vec<WorkloadSystem>,
+    ) -> Result<(), error::AddWorkload> {
+        let order = order(&systems)?;
+
+        let batch_start = self.batch.len();
+        // systems' index in `systems` for each batch
+        let mut new_batch: Vec<Vec<usize>> = Vec::new();
+        let mut conflicts = vec![None; systems.len()];
+
+        for &(index, ref after) in &order {
+            let borrow_infos = &systems[index].borrow_infos;
+            let mut batch_index = new_batch.len();
+            'batches: for batch in new_batch.iter().rev() {
+                for &other in batch {
+                    if after.contains(&other) {
+                        conflicts[index] = Some(Conflict::Ordering {
+                            other_system: systems[other].name,
+                        });
+                        break 'batches;
+                    }
+                    if let Some((borrow, other_borrow)) =
+                        conflict(borrow_infos, &systems[other].borrow_infos)
+                    {
+                        conflicts[index] = Some(Conflict::Borrow {
+                            borrow,
+                            other_system: systems[other].name,
+                            other_borrow,
+                        });
+                        break 'batches;
+                    }
+                }
+                batch_index -= 1;
+            }
+
+            if batch_index == new_batch.len() {
+                new_batch.push(vec![index]);
+            } else {
+                new_batch[batch_index].push(index);
+            }
+        }
+
+        let first_system = self.systems.len();
+        for batch in &mut new_batch {
+            batch.sort_unstable();
+        }
+        self.batch.extend(new_batch.into_iter().map(|batch| {
+            batch
+                .into_iter()
+                .map(|index| first_system + index)
+                .collect::<Vec<_>>()
+                .into_boxed_slice()
+        }));
+
+        for (system, conflict) in systems.into_iter().zip(conflicts) {
+            self.system_infos.push(SystemInfo {
+                name: system.name,
+                borrow: system.borrow_infos,
+                conflict,
+            });
+            self.systems.push(system.system);
+            self.last_runs.push(system.last_run);
+        }
+
+        if self.workloads.is_empty() {
+            self.default = batch_start..(self.batch.len());
+        }
+
+        self.workloads.insert(name, batch_start..(self.batch.len()));
+
+        Ok(())
+    }
+}
+
+/// Returns the systems' indices in an order respecting all ordering constraints,
+/// each system comes with the list of systems it has to run after.
+///
+/// When systems aren't constrained, they stay in the order they were given.
+#[allow(clippy::type_complexity)]
+fn order(systems: &[WorkloadSystem]) -> Result<Vec<(usize, Vec<usize>)>, error::AddWorkload> {
+    let matching = |target: &Target| -> Result<Vec<usize>, error::AddWorkload> {
+        let matching: Vec<_> = systems
+            .iter()
+            .enumerate()
+            .filter(|(_, system)| match target {
+                Target::System(type_id, _) => system.type_id == *type_id,
+                Target::Label(label) => system.labels.contains(label),
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        if matching.is_empty() {
+            match target {
+                Target::System(_, name) => Err(error::AddWorkload::UnknownSystem(name)),
+                Target::Label(label) => Err(error::AddWorkload::UnknownLabel(label)),
+            }
+        } else {
+            Ok(matching)
+        }
+    };
+
+    let mut after = vec![Vec::new(); systems.len()];
+    for (index, system) in systems.iter().enumerate() {
+        for target in &system.after {
+            for other in matching(target)? {
+                if other != index && !after[index].contains(&other) {
+                    after[index].push(other);
+                }
+            }
+        }
+        for target in &system.before {
+            for other in matching(target)? {
+                if other != index && !after[other].contains(&index) {
+                    after[other].push(index);
+                }
+            }
+        }
+    }
+
+    let mut placed = vec![false; systems.len()];
+    let mut order = Vec::with_capacity(systems.len());
+    while order.len() < systems.len() {
+        let next = (0..systems.len())
+            .find(|&index| !placed[index] && after[index].iter().all(|&other| placed[other]));
+
+        if let Some(next) = next {
+            placed[next] = true;
+            order.push((next, std::mem::take(&mut after[next])));
+        } else {
+            // all remaining systems wait on each other
+            let index = (0..systems.len()).find(|&index| !placed[index]).unwrap();
+            return Err(error::AddWorkload::Cycle(systems[index].name));
+        }
+    }
+
+    Ok(order)
+}
+
+/// Returns the first pair of borrows preventing a system borrowing `borrow_infos`
+/// to run at the same time as one borrowing `other_borrow_infos`.
+#[allow(clippy::type_complexity)]
+fn conflict(
+    borrow_infos: &[(TypeId, Mutation)],
+    other_borrow_infos: &[(TypeId, Mutation)],
+) -> Option<((TypeId, Mutation), (TypeId, Mutation))> {
+    for &(type_id, mutation) in borrow_infos {
+        match mutation {
+            Mutation::Shared => {
+                for &(other_type_id, other_mutation) in other_borrow_infos {
+                    #[cfg(feature = "parallel")]
+                    {
+                        if type_id == other_type_id && other_mutation == Mutation::Unique
+                            || (other_type_id == TypeId::of::<AllStorages>()
+                                && type_id != TypeId::of::<crate::ThreadPool>())
+                        {
+                            return Some(((type_id, mutation), (other_type_id, other_mutation)));
                         }
-                        if conflict {
-                            break;
-                        } else {
-                            batch_index -= 1;
+                    }
+                    #[cfg(not(feature = "parallel"))]
+                    {
+                        if type_id == other_type_id && other_mutation == Mutation::Unique
+                            || other_type_id == TypeId::of::<AllStorages>()
+                        {
+                            return Some(((type_id, mutation), (other_type_id, other_mutation)));
                         }
                     }
-
-                    if batch_index == batch_info.len() {
-                        new_batch.push(vec![pipeline.systems.len()]);
-                        batch_info.push(borrow_infos);
-                    } else {
-                        new_batch[batch_index].push(pipeline.systems.len());
-                        batch_info[batch_index].append(&mut borrow_infos);
+                }
+            }
+            Mutation::Unique => {
+                for &(other_type_id, other_mutation) in other_borrow_infos {
+                    #[cfg(feature = "parallel")]
+                    {
+                        if type_id == other_type_id
+                            || (type_id == TypeId::of::<AllStorages>()
+                                && (other_type_id != TypeId::of::<crate::ThreadPool>()
+                                    && other_type_id != TypeId::of::<Entities>()))
+                        {
+                            return Some(((type_id, mutation), (other_type_id, other_mutation)));
+                        }
+                    }
+                    #[cfg(not(feature = "parallel"))]
+                    {
+                        if type_id == other_type_id
+                            || (type_id == TypeId::of::<AllStorages>()
+                                && other_type_id != TypeId::of::<Entities>())
+                        {
+                            return Some(((type_id, mutation), (other_type_id, other_mutation)));
+                        }
                     }
+                }
+            }
+        }
+    }
 
-                    pipeline.systems.push(Box::new(|world| {
-                        $type::try_dispatch(world).unwrap()
-                    }));
-                })+
+    None
+}
 
-                pipeline.batch.extend(new_batch.into_iter().map(|batch| batch.into_boxed_slice()));
+pub trait Workload {
+    fn into_workload(self, name: String, pipeline: &mut Pipeline)
+        -> Result<(), error::AddWorkload>;
+}
 
-                if pipeline.workloads.is_empty() {
-                    pipeline.default = batch_start..(pipeline.batch.len());
-                }
+impl<T: for<'a> System<'a> + Send + Sync + 'static> Workload for T {
+    fn into_workload(
+        self,
+        name: String,
+        pipeline: &mut Pipeline,
+    ) -> Result<(), error::AddWorkload> {
+        pipeline.add_workload(name, vec![self.into_workload_system()])
+    }
+}
 
-                pipeline.workloads.insert(name, batch_start..(pipeline.batch.len()));
+impl Workload for WorkloadSystem {
+    fn into_workload(
+        self,
+        name: String,
+        pipeline: &mut Pipeline,
+    ) -> Result<(), error::AddWorkload> {
+        pipeline.add_workload(name, vec![self])
+    }
+}
+
+impl<T: IntoWorkloadSystem> Workload for (T,) {
+    fn into_workload(
+        self,
+        name: String,
+        pipeline: &mut Pipeline,
+    ) -> Result<(), error::AddWorkload> {
+        pipeline.add_workload(name, vec![self.0.into_workload_system()])
+    }
+}
+
+macro_rules! impl_pipeline {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: IntoWorkloadSystem),+> Workload for ($($type,)+) {
+            fn into_workload(self, name: String, pipeline: &mut Pipeline) -> Result<(), error::AddWorkload> {
+                pipeline.add_workload(name, vec![$(self.$index.into_workload_system(),)+])
             }
         }
     }
@@ -173,7 +495,9 @@ fn single_immutable() {
     }
 
     let mut pipeline = Pipeline::default();
-    System1.into_workload("System1".to_string(), &mut pipeline);
+    System1
+        .into_workload("System1".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 1);
     assert_eq!(pipeline.batch.len(), 1);
     assert_eq!(&*pipeline.batch[0], &[0]);
@@ -190,7 +514,9 @@ fn single_mutable() {
     }
 
     let mut pipeline = Pipeline::default();
-    System1.into_workload("System1".to_string(), &mut pipeline);
+    System1
+        .into_workload("System1".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 1);
     assert_eq!(pipeline.batch.len(), 1);
     assert_eq!(&*pipeline.batch[0], &[0]);
@@ -212,7 +538,9 @@ fn multiple_immutable() {
     }
 
     let mut pipeline = Pipeline::default();
-    (System1, System2).into_workload("Systems".to_string(), &mut pipeline);
+    (System1, System2)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 2);
     assert_eq!(pipeline.batch.len(), 1);
     assert_eq!(&*pipeline.batch[0], &[0, 1]);
@@ -234,7 +562,9 @@ fn multiple_mutable() {
     }
 
     let mut pipeline = Pipeline::default();
-    (System1, System2).into_workload("Systems".to_string(), &mut pipeline);
+    (System1, System2)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 2);
     assert_eq!(pipeline.batch.len(), 2);
     assert_eq!(&*pipeline.batch[0], &[0]);
@@ -257,7 +587,9 @@ fn multiple_mixed() {
     }
 
     let mut pipeline = Pipeline::default();
-    (System1, System2).into_workload("Systems".to_string(), &mut pipeline);
+    (System1, System2)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 2);
     assert_eq!(pipeline.batch.len(), 2);
     assert_eq!(&*pipeline.batch[0], &[0]);
@@ -267,7 +599,9 @@ fn multiple_mixed() {
     assert_eq!(pipeline.default, 0..2);
 
     let mut pipeline = Pipeline::default();
-    (System2, System1).into_workload("Systems".to_string(), &mut pipeline);
+    (System2, System1)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 2);
     assert_eq!(pipeline.batch.len(), 2);
     assert_eq!(&*pipeline.batch[0], &[0]);
@@ -290,7 +624,9 @@ fn all_storages() {
     }
 
     let mut pipeline = Pipeline::default();
-    (System1, System2).into_workload("Systems".to_string(), &mut pipeline);
+    (System1, System2)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 2);
     assert_eq!(pipeline.batch.len(), 2);
     assert_eq!(&*pipeline.batch[0], &[0]);
@@ -300,7 +636,9 @@ fn all_storages() {
     assert_eq!(pipeline.default, 0..2);
 
     let mut pipeline = Pipeline::default();
-    (System2, System1).into_workload("Systems".to_string(), &mut pipeline);
+    (System2, System1)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
     assert_eq!(pipeline.systems.len(), 2);
     assert_eq!(pipeline.batch.len(), 2);
     assert_eq!(&*pipeline.batch[0], &[0]);
@@ -309,3 +647,128 @@ fn all_storages() {
     assert_eq!(pipeline.workloads.get("Systems"), Some(&(0..2)));
     assert_eq!(pipeline.default, 0..2);
 }
+#[test]
+fn ordering() {
+    use super::ordering::SystemOrdering;
+
+    struct System1;
+    impl<'a> System<'a> for System1 {
+        type Data = (&'a usize,);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+    struct System2;
+    impl<'a> System<'a> for System2 {
+        type Data = (&'a u32,);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+    struct System3;
+    impl<'a> System<'a> for System3 {
+        type Data = (&'a u64,);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+
+    let mut pipeline = Pipeline::default();
+    (System1.after::<System2>(), System2)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
+    assert_eq!(pipeline.systems.len(), 2);
+    assert_eq!(pipeline.batch.len(), 2);
+    assert_eq!(&*pipeline.batch[0], &[1]);
+    assert_eq!(&*pipeline.batch[1], &[0]);
+
+    let mut pipeline = Pipeline::default();
+    (System1, System2.before::<System1>(), System3)
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
+    assert_eq!(pipeline.batch.len(), 2);
+    assert_eq!(&*pipeline.batch[0], &[1, 2]);
+    assert_eq!(&*pipeline.batch[1], &[0]);
+
+    let mut pipeline = Pipeline::default();
+    (
+        System3.after_label("logic"),
+        System1.label("logic"),
+        System2.label("logic"),
+    )
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
+    assert_eq!(pipeline.batch.len(), 2);
+    assert_eq!(&*pipeline.batch[0], &[1, 2]);
+    assert_eq!(&*pipeline.batch[1], &[0]);
+}
+#[test]
+fn ordering_errors() {
+    use super::ordering::SystemOrdering;
+
+    struct System1;
+    impl<'a> System<'a> for System1 {
+        type Data = (&'a usize,);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+    struct System2;
+    impl<'a> System<'a> for System2 {
+        type Data = (&'a u32,);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+
+    let mut pipeline = Pipeline::default();
+    assert!(matches!(
+        (System1.after::<System2>(), System2.after::<System1>())
+            .into_workload("Systems".to_string(), &mut pipeline),
+        Err(error::AddWorkload::Cycle(_))
+    ));
+
+    assert!(matches!(
+        (System1.after::<u8>(), System2).into_workload("Systems".to_string(), &mut pipeline),
+        Err(error::AddWorkload::UnknownSystem(_))
+    ));
+
+    assert_eq!(
+        (System1.before_label("render"), System2)
+            .into_workload("Systems".to_string(), &mut pipeline),
+        Err(error::AddWorkload::UnknownLabel("render"))
+    );
+    assert!(pipeline.workloads.is_empty());
+}
+#[test]
+fn conflicts() {
+    use super::ordering::SystemOrdering;
+
+    struct System1;
+    impl<'a> System<'a> for System1 {
+        type Data = (&'a mut usize,);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+    struct System2;
+    impl<'a> System<'a> for System2 {
+        type Data = (&'a u32, &'a usize);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+    struct System3;
+    impl<'a> System<'a> for System3 {
+        type Data = (&'a u64,);
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+
+    let mut pipeline = Pipeline::default();
+    (System1, System2, System3.after::<System2>())
+        .into_workload("Systems".to_string(), &mut pipeline)
+        .unwrap();
+    assert_eq!(pipeline.batch.len(), 3);
+    assert_eq!(pipeline.system_infos.len(), 3);
+    assert_eq!(pipeline.system_infos[0].conflict, None);
+    assert_eq!(
+        pipeline.system_infos[1].conflict,
+        Some(Conflict::Borrow {
+            borrow: (TypeId::of::<usize>(), Mutation::Shared),
+            other_system: type_name::<System1>(),
+            other_borrow: (TypeId::of::<usize>(), Mutation::Unique),
+        })
+    );
+    assert_eq!(
+        pipeline.system_infos[2].conflict,
+        Some(Conflict::Ordering {
+            other_system: type_name::<System2>(),
+        })
+    );
+}
@@ -0,0 +1,173 @@
+use crate::error;
+use crate::sparse_set::{check_storages, DeserializeSparseSet, SerializeSparseSet};
+use crate::storage::{AllStorages, Entities, Storage};
+use crate::world::World;
+use serde::de::{DeserializeOwned, Error as DeError};
+use serde::ser::{Error as SerError, SerializeTuple};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::{type_name, TypeId};
+
+// Serialize multiple storages at once
+pub trait SerializeStorages {
+    fn serialize_storages<S: Serializer>(
+        all_storages: &AllStorages,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+// Deserialize multiple storages at once
+pub trait DeserializeStorages {
+    fn deserialize_storages<'de, De: Deserializer<'de>>(
+        all_storages: &mut AllStorages,
+        deserializer: De,
+    ) -> Result<(), De::Error>;
+}
+
+impl World {
+    /// Serializes the entities and all `T` storages, unique storages included.
+    ///
+    /// Packs are saved alongside the components, all storages packed with one of `T` have to be part of `T`.\
+    /// Change tracking and hooks aren't saved, they have to be set up again on the deserialized `World`.
+    ///
+    /// `T` has to be a tuple even for a single type.
+    /// In this case use (T,).
+    /// # Example
+    /// ```
+    /// # use shipyard::prelude::*;
+    /// let world = World::new::<(usize, u32)>();
+    ///
+    /// world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(|(mut entities, mut usizes, mut u32s)| {
+    ///     entities.add_entity((&mut usizes, &mut u32s), (0usize, 1u32));
+    /// });
+    ///
+    /// let mut bytes = Vec::new();
+    /// world
+    ///     .serialize::<(usize, u32), _>(&mut serde_json::Serializer::new(&mut bytes))
+    ///     .unwrap();
+    ///
+    /// let world = World::deserialize::<(usize, u32), _>(
+    ///     &mut serde_json::Deserializer::from_slice(&bytes)
+    /// ).unwrap();
+    ///
+    /// world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+    ///     assert_eq!((&usizes, &u32s).iter().next(), Some((&0, &1)));
+    /// });
+    /// ```
+    pub fn serialize<T: SerializeStorages, S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let all_storages = self
+            .storages
+            .try_borrow()
+            .map_err(|err| S::Error::custom(error::GetStorage::AllStoragesBorrow(err)))?;
+        T::serialize_storages(&all_storages, serializer)
+    }
+    /// Creates a new `World` from entities and `T` storages previously serialized with [serialize].
+    ///
+    /// `T` has to list the same types in the same order as when the `World` was serialized.\
+    /// Storages are checked against each other and against the entities, inconsistent data is rejected.\
+    /// Storages don't track changes and don't have hooks, use `track_changes` and `set_hooks` to set them up again.
+    ///
+    /// `T` has to be a tuple even for a single type.
+    /// In this case use (T,).
+    ///
+    /// [serialize]: struct.World.html#method.serialize
+    pub fn deserialize<'de, T: DeserializeStorages, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let world = World::default();
+        {
+            let mut all_storages = world
+                .storages
+                .try_borrow_mut()
+                .map_err(|err| D::Error::custom(error::GetStorage::AllStoragesBorrow(err)))?;
+            T::deserialize_storages(&mut all_storages, deserializer)?;
+        }
+        Ok(world)
+    }
+}
+
+macro_rules! impl_serialization {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: 'static + Serialize),+> SerializeStorages for ($($type,)+) {
+            fn serialize_storages<S: Serializer>(
+                all_storages: &AllStorages,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                let types = [$((type_name::<$type>(), TypeId::of::<$type>()),)+];
+
+                let entities = all_storages.0[&TypeId::of::<Entities>()]
+                    .entities()
+                    .map_err(|err| S::Error::custom(error::GetStorage::Entities(err)))?;
+                let storages = ($(
+                    all_storages
+                        .0
+                        .get(&TypeId::of::<$type>())
+                        .ok_or_else(|| S::Error::custom(error::GetStorage::MissingComponent(type_name::<$type>())))?
+                        .sparse_set::<$type>()
+                        .map_err(|err| S::Error::custom(error::GetStorage::StorageBorrow((type_name::<$type>(), err))))?,
+                )+);
+
+                let mut tup = serializer.serialize_tuple(2)?;
+                tup.serialize_element(&*entities)?;
+                tup.serialize_element(&($(
+                    SerializeSparseSet {
+                        sparse_set: &storages.$index,
+                        types: &types,
+                    },
+                )+))?;
+                tup.end()
+            }
+        }
+
+        impl<$($type: 'static + Send + Sync + DeserializeOwned),+> DeserializeStorages for ($($type,)+) {
+            fn deserialize_storages<'de, De: Deserializer<'de>>(
+                all_storages: &mut AllStorages,
+                deserializer: De,
+            ) -> Result<(), De::Error> {
+                let types = [$((type_name::<$type>(), TypeId::of::<$type>()),)+];
+
+                let (entities, storages): (Entities, ($(DeserializeSparseSet<$type>,)+)) =
+                    Deserialize::deserialize(deserializer)?;
+
+                let sparse_sets = ($(
+                    storages.$index.into_sparse_set(&types).map_err(De::Error::custom)?,
+                )+);
+                check_storages(
+                    &[$(sparse_sets.$index.deserialized(TypeId::of::<$type>()),)+],
+                    &entities.view(),
+                )
+                .map_err(De::Error::custom)?;
+
+                *all_storages.0[&TypeId::of::<Entities>()]
+                    .entities_mut()
+                    .map_err(|err| De::Error::custom(error::GetStorage::Entities(err)))? = entities;
+
+                $({
+                    let sparse_set = sparse_sets.$index;
+                    *all_storages
+                        .0
+                        .entry(TypeId::of::<$type>())
+                        .or_insert_with(Storage::new::<$type>)
+                        .sparse_set_mut::<$type>()
+                        .map_err(|err| De::Error::custom(error::GetStorage::StorageBorrow((type_name::<$type>(), err))))? = sparse_set;
+                })+
+
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! serialization {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_serialization![$(($type, $index))*];
+        serialization![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_serialization![$(($type, $index))*];
+    }
+}
+
+serialization![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
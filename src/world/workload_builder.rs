@@ -0,0 +1,107 @@
+use super::pipeline::{IntoWorkloadSystem, Pipeline, Workload, WorkloadSystem};
+use crate::error;
+use crate::run::{SystemData, TrySystem};
+use std::error::Error;
+
+/// Builds a workload one system at a time, without any limit on the number of systems.
+///
+/// Batches are computed the same way as for tuples of systems.
+/// # Example
+/// ```
+/// # use shipyard::prelude::*;
+/// struct Move;
+/// impl<'a> System<'a> for Move {
+///     type Data = (&'a mut usize, &'a u32);
+///     fn run((usizes, u32s): <Self::Data as SystemData>::View) {
+///         (usizes, u32s).iter().for_each(|(x, &y)| *x += y as usize);
+///     }
+/// }
+///
+/// let world = World::new::<(usize, u32)>();
+///
+/// world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(|(mut entities, mut usizes, mut u32s)| {
+///     entities.add_entity((&mut usizes, &mut u32s), (0, 1));
+/// });
+///
+/// let mut builder = WorkloadBuilder::default();
+/// for _ in 0..20 {
+///     builder = builder.with_system(Move);
+/// }
+/// let builder = builder.with_fn::<&usize, _>(|usizes| {
+///     usizes.iter().for_each(|&x| assert_eq!(x, 20));
+/// });
+///
+/// world.add_workload("Frame", builder);
+/// world.run_default();
+/// ```
+#[derive(Default)]
+pub struct WorkloadBuilder {
+    systems: Vec<WorkloadSystem>,
+}
+
+impl WorkloadBuilder {
+    /// Adds `system` at the end of the workload.
+    ///
+    /// `system` can be a [System], an ordered system or a [WorkloadSystem].
+    ///
+    /// [System]: trait.System.html
+    /// [WorkloadSystem]: struct.WorkloadSystem.html
+    pub fn with_system<S: IntoWorkloadSystem>(mut self, system: S) -> Self {
+        self.systems.push(system.into_workload_system());
+        self
+    }
+    /// Adds a closure at the end of the workload, `T` is what the closure borrows.
+    ///
+    /// See [WorkloadSystem::from_fn].
+    ///
+    /// [WorkloadSystem::from_fn]: struct.WorkloadSystem.html#method.from_fn
+    pub fn with_fn<T, F>(self, f: F) -> Self
+    where
+        T: for<'a> SystemData<'a>,
+        F: for<'a> Fn(<T as SystemData<'a>>::View) + Send + Sync + 'static,
+    {
+        self.with_system(WorkloadSystem::from_fn::<T, F>(f))
+    }
+    /// Adds a fallible `system` at the end of the workload.
+    ///
+    /// See [WorkloadSystem::try_new].
+    ///
+    /// [WorkloadSystem::try_new]: struct.WorkloadSystem.html#method.try_new
+    pub fn with_try_system<S: for<'a> TrySystem<'a> + Send + Sync + 'static>(
+        self,
+        system: S,
+    ) -> Self {
+        self.with_system(WorkloadSystem::try_new(system))
+    }
+    /// Adds a fallible closure at the end of the workload, `T` is what the closure borrows.
+    ///
+    /// See [WorkloadSystem::from_try_fn].
+    ///
+    /// [WorkloadSystem::from_try_fn]: struct.WorkloadSystem.html#method.from_try_fn
+    pub fn with_try_fn<T, E, F>(self, f: F) -> Self
+    where
+        T: for<'a> SystemData<'a>,
+        E: Error + Send + Sync + 'static,
+        F: for<'a> Fn(<T as SystemData<'a>>::View) -> Result<(), E> + Send + Sync + 'static,
+    {
+        self.with_system(WorkloadSystem::from_try_fn::<T, E, F>(f))
+    }
+    /// Returns the number of systems in the workload.
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+    /// Returns true if the workload doesn't contain any system.
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+}
+
+impl Workload for WorkloadBuilder {
+    fn into_workload(
+        self,
+        name: String,
+        pipeline: &mut Pipeline,
+    ) -> Result<(), error::AddWorkload> {
+        pipeline.add_workload(name, self.systems)
+    }
+}
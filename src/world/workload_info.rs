@@ -0,0 +1,41 @@
+use crate::run::Mutation;
+use std::any::TypeId;
+
+/// Batches computed for a workload.
+///
+/// Batches run one after the other, systems inside a batch run in parallel.
+#[derive(Clone, Debug)]
+pub struct WorkloadInfo {
+    pub name: String,
+    pub batches: Vec<BatchInfo>,
+}
+
+/// Systems running in parallel.
+#[derive(Clone, Debug)]
+pub struct BatchInfo {
+    pub systems: Vec<SystemInfo>,
+}
+
+/// A system and what it borrows.
+#[derive(Clone, Debug)]
+pub struct SystemInfo {
+    /// Name of the system's type.
+    pub name: &'static str,
+    pub borrow: Vec<(TypeId, Mutation)>,
+    /// Why the system couldn't be part of the previous batch.\
+    /// `None` when the system is part of the workload's first batch.
+    pub conflict: Option<Conflict>,
+}
+
+/// Reason a system was put in a later batch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Conflict {
+    /// `borrow` can't happen while `other_system` holds `other_borrow`.
+    Borrow {
+        borrow: (TypeId, Mutation),
+        other_system: &'static str,
+        other_borrow: (TypeId, Mutation),
+    },
+    /// The system has to run after `other_system`.
+    Ordering { other_system: &'static str },
+}
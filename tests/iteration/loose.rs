@@ -491,3 +491,127 @@ fn enumerate_filter_map_with_id() {
         assert!(iter.next().is_none());
     });
 }
+
+#[test]
+fn optional() {
+    let world = World::new::<(u32, i16)>();
+
+    world.loose_pack::<(u32,), (i16,)>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(|(mut entities, mut u32s, mut i16s)| {
+        entities.add_entity((&mut u32s, &mut i16s), (0, 10));
+        entities.add_entity(&mut u32s, 1);
+        entities.add_entity((&mut u32s, &mut i16s), (2, 12));
+        entities.add_entity(&mut i16s, 13);
+        entities.add_entity((&mut u32s, &mut i16s), (4, 14));
+    });
+
+    world.run::<(&u32, &i16), _, _>(|(u32s, i16s)| {
+        let mut iter = (&u32s, Optional(&i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (&0, Some(&10)));
+        assert_eq!(iter.next().unwrap(), (&2, Some(&12)));
+        assert_eq!(iter.next().unwrap(), (&4, Some(&14)));
+        assert_eq!(iter.next().unwrap(), (&1, None));
+        assert!(iter.next().is_none());
+
+        let mut iter = (Optional(&u32s), &i16s).iter();
+        assert_eq!(iter.next().unwrap(), (Some(&0), &10));
+        assert_eq!(iter.next().unwrap(), (Some(&2), &12));
+        assert_eq!(iter.next().unwrap(), (None, &13));
+        assert_eq!(iter.next().unwrap(), (Some(&4), &14));
+        assert!(iter.next().is_none());
+    });
+}
+
+#[test]
+fn into_iterator() {
+    let world = World::new::<(u32, i16)>();
+
+    world.loose_pack::<(u32,), (i16,)>();
+
+    let (key0, _, key2) = world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(
+        |(mut entities, mut u32s, mut i16s)| {
+            (
+                entities.add_entity((&mut u32s, &mut i16s), (0, 10)),
+                entities.add_entity(&mut u32s, 1),
+                entities.add_entity((&mut u32s, &mut i16s), (2, 12)),
+            )
+        },
+    );
+
+    world.run::<(&u32, &i16), _, _>(|(u32s, i16s)| {
+        let iter = (&u32s, &i16s).iter().with_id().into_iterator();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>(),
+            vec![(key2, (&2, &12)), (key0, (&0, &10))]
+        );
+    });
+}
+
+#[test]
+fn chunk() {
+    let world = World::new::<(u32, u64, i16)>();
+
+    world.loose_pack::<(u32, u64), (i16,)>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut u64, &mut i16), _, _>(
+        |(mut entities, mut u32s, mut u64s, mut i16s)| {
+            entities.add_entity((&mut u32s, &mut u64s, &mut i16s), (0, 10, 20));
+            entities.add_entity((&mut u32s, &mut u64s), (1, 11));
+            entities.add_entity((&mut u32s, &mut u64s, &mut i16s), (2, 12, 22));
+            entities.add_entity((&mut u32s, &mut u64s, &mut i16s), (3, 13, 23));
+            entities.add_entity((&mut u32s, &mut u64s, &mut i16s), (4, 14, 24));
+        },
+    );
+
+    world.run::<(&u32, &u64), _, _>(|(u32s, u64s)| {
+        assert!((&u32s, &u64s).iter().into_chunk(3).is_err());
+        assert_eq!((&u32s, &u64s).iter().count(), 5);
+
+        let mut iter = (&u32s, &u64s).packed_prefix().ok().unwrap().into_chunk(3);
+        assert_eq!(iter.next(), Some((&[0, 2, 3][..], &[10, 12, 13][..])));
+        assert_eq!(iter.next(), Some((&[4][..], &[14][..])));
+        assert!(iter.next().is_none());
+
+        let mut iter = (&u32s, &u64s)
+            .packed_prefix()
+            .ok()
+            .unwrap()
+            .into_chunk_exact(3);
+        assert_eq!(iter.next(), Some((&[0, 2, 3][..], &[10, 12, 13][..])));
+        assert!(iter.next().is_none());
+        assert_eq!(iter.remainder(), (&[4][..], &[14][..]));
+    });
+    world.run::<(&mut u32, &u64), _, _>(|(mut u32s, u64s)| {
+        (&mut u32s, &u64s)
+            .packed_prefix()
+            .ok()
+            .unwrap()
+            .into_chunk(2)
+            .for_each(|(x, y)| {
+                x.iter_mut().zip(y).for_each(|(x, &y)| *x += y as u32);
+            });
+
+        assert_eq!(
+            (&u32s).iter().into_iterator().copied().collect::<Vec<_>>(),
+            vec![10, 14, 16, 18, 1]
+        );
+
+        // entities outside the packed prefix are still visited by iter
+        (&mut u32s, &u64s).iter().for_each(|(x, &y)| *x += y as u32);
+
+        assert_eq!(
+            (&u32s).iter().into_iterator().copied().collect::<Vec<_>>(),
+            vec![20, 26, 29, 32, 12]
+        );
+    });
+
+    world.run::<(&u32, &i16), _, _>(|(u32s, i16s)| {
+        assert!((&u32s, &i16s).iter().into_chunk(2).is_err());
+        assert!((&u32s, &i16s).packed_prefix().is_err());
+    });
+    world.run::<(&u32, &u64), _, _>(|(u32s, u64s)| {
+        assert!((&u32s, !&u64s).packed_prefix().is_err());
+    });
+}
@@ -479,3 +479,205 @@ fn enumerate_filter_map_with_id() {
         assert!(iter.next().is_none());
     });
 }
+
+#[test]
+fn optional() {
+    let world = World::new::<(u32, i16)>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(|(mut entities, mut u32s, mut i16s)| {
+        entities.add_entity((&mut u32s, &mut i16s), (0, 10));
+        entities.add_entity(&mut u32s, 1);
+        entities.add_entity((&mut u32s, &mut i16s), (2, 12));
+        entities.add_entity(&mut i16s, 13);
+        entities.add_entity((&mut u32s, &mut i16s), (4, 14));
+    });
+
+    world.run::<(&u32, &i16), _, _>(|(u32s, i16s)| {
+        let mut iter = (&u32s, Optional(&i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (&0, Some(&10)));
+        assert_eq!(iter.next().unwrap(), (&1, None));
+        assert_eq!(iter.next().unwrap(), (&2, Some(&12)));
+        assert_eq!(iter.next().unwrap(), (&4, Some(&14)));
+        assert!(iter.next().is_none());
+
+        let mut iter = (Optional(&u32s), &i16s).iter();
+        assert_eq!(iter.next().unwrap(), (Some(&0), &10));
+        assert_eq!(iter.next().unwrap(), (Some(&2), &12));
+        assert_eq!(iter.next().unwrap(), (None, &13));
+        assert_eq!(iter.next().unwrap(), (Some(&4), &14));
+        assert!(iter.next().is_none());
+
+        assert!((Optional(&u32s), Optional(&i16s)).iter().next().is_none());
+    });
+
+    world.run::<(&mut u32, &mut i16), _, _>(|(mut u32s, mut i16s)| {
+        (&mut u32s, Optional(&mut i16s))
+            .iter()
+            .for_each(|(x, y)| match y {
+                Some(y) => *y += *x as i16,
+                None => *x += 100,
+            });
+
+        let mut iter = (&u32s, Optional(&i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (&0, Some(&10)));
+        assert_eq!(iter.next().unwrap(), (&101, None));
+        assert_eq!(iter.next().unwrap(), (&2, Some(&14)));
+        assert_eq!(iter.next().unwrap(), (&4, Some(&18)));
+        assert!(iter.next().is_none());
+    });
+}
+
+#[test]
+fn any_of() {
+    let world = World::new::<(u32, i16, u64)>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut i16, &mut u64), _, _>(
+        |(mut entities, mut u32s, mut i16s, mut u64s)| {
+            entities.add_entity((&mut u32s, &mut i16s), (0, 10));
+            entities.add_entity(&mut u32s, 1);
+            entities.add_entity((&mut u32s, &mut i16s, &mut u64s), (2, 12, 22));
+            entities.add_entity(&mut i16s, 13);
+            entities.add_entity(&mut u64s, 24);
+        },
+    );
+
+    world.run::<(&u32, &i16, &u64), _, _>(|(u32s, i16s, u64s)| {
+        let mut iter = AnyOf((&u32s, &i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (Some(&0), Some(&10)));
+        assert_eq!(iter.next().unwrap(), (Some(&1), None));
+        assert_eq!(iter.next().unwrap(), (Some(&2), Some(&12)));
+        assert_eq!(iter.next().unwrap(), (None, Some(&13)));
+        assert!(iter.next().is_none());
+
+        // the smallest storage drives the iteration
+        let mut iter = (AnyOf((&u32s, &i16s)), &u64s).iter().with_id();
+        let (id, (any, u64)) = iter.next().unwrap();
+        assert_eq!((any, u64), ((Some(&2), Some(&12)), &22));
+        assert_eq!(Some(u64), u64s.get(id));
+        assert!(iter.next().is_none());
+
+        // the union drives the iteration
+        let mut iter = (AnyOf((&i16s, &u64s)), Optional(&u32s)).iter();
+        assert_eq!(iter.next().unwrap(), ((Some(&10), None), Some(&0)));
+        assert_eq!(iter.next().unwrap(), ((Some(&12), Some(&22)), Some(&2)));
+        assert_eq!(iter.next().unwrap(), ((Some(&13), None), None));
+        assert_eq!(iter.next().unwrap(), ((None, Some(&24)), None));
+        assert!(iter.next().is_none());
+
+        let mut iter = (&u32s, Optional(AnyOf((&i16s, &u64s)))).iter();
+        assert_eq!(iter.next().unwrap(), (&0, Some((Some(&10), None))));
+        assert_eq!(iter.next().unwrap(), (&1, None));
+        assert_eq!(iter.next().unwrap(), (&2, Some((Some(&12), Some(&22)))));
+        assert!(iter.next().is_none());
+
+        // AnyOf can't be sliced
+        assert!(AnyOf((&u32s, &i16s)).iter().into_chunk(1).is_err());
+        assert!(AnyOf((&u32s, &i16s)).iter().into_chunk_exact(1).is_err());
+        assert!(Optional(AnyOf((&u32s, &i16s)))
+            .iter()
+            .into_chunk(1)
+            .is_err());
+    });
+
+    world.run::<(&mut u32, &mut i16), _, _>(|(mut u32s, mut i16s)| {
+        AnyOf((&mut u32s, &mut i16s))
+            .iter()
+            .for_each(|(x, y)| match (x, y) {
+                (Some(x), Some(y)) => *y += *x as i16,
+                (Some(x), None) => *x += 100,
+                (None, Some(y)) => *y += 100,
+                (None, None) => unreachable!(),
+            });
+
+        let mut iter = AnyOf((&u32s, &i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (Some(&0), Some(&10)));
+        assert_eq!(iter.next().unwrap(), (Some(&101), None));
+        assert_eq!(iter.next().unwrap(), (Some(&2), Some(&14)));
+        assert_eq!(iter.next().unwrap(), (None, Some(&113)));
+        assert!(iter.next().is_none());
+    });
+}
+
+#[test]
+fn into_iterator() {
+    let world = World::new::<(u32, i16)>();
+
+    let (key0, _, key2, _) = world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(
+        |(mut entities, mut u32s, mut i16s)| {
+            (
+                entities.add_entity((&mut u32s, &mut i16s), (0, 10)),
+                entities.add_entity(&mut u32s, 1),
+                entities.add_entity((&mut u32s, &mut i16s), (2, 12)),
+                entities.add_entity(&mut i16s, 13),
+            )
+        },
+    );
+
+    world.run::<(&u32, &i16), _, _>(|(u32s, i16s)| {
+        let iter = (&u32s, &i16s).iter().with_id().into_iterator();
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>(),
+            vec![(key2, (&2, &12)), (key0, (&0, &10))]
+        );
+
+        let mut iter = (&u32s, &i16s).iter();
+        assert_eq!(iter.next_back(), Some((&2, &12)));
+        assert_eq!(iter.next_back(), Some((&0, &10)));
+        assert!(iter.next().is_none());
+
+        assert_eq!(
+            (&u32s, &i16s)
+                .iter()
+                .into_iterator()
+                .map(|(&x, &y)| x as i32 + y as i32)
+                .collect::<Vec<_>>(),
+            vec![10, 14]
+        );
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_iter() {
+    use rayon::prelude::*;
+
+    let world = World::new::<(u32, i16)>();
+
+    let entities = world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(
+        |(mut entities, mut u32s, mut i16s)| {
+            (0..100u32)
+                .map(|i| {
+                    if i % 3 == 0 {
+                        entities.add_entity(&mut u32s, i)
+                    } else {
+                        entities.add_entity((&mut u32s, &mut i16s), (i, i as i16))
+                    }
+                })
+                .collect::<Vec<_>>()
+        },
+    );
+
+    world.run::<(&u32, &i16), _, _>(|(u32s, i16s)| {
+        let sum: u32 = (&u32s, &i16s).par_iter().map(|(&x, &y)| x + y as u32).sum();
+        assert_eq!(
+            sum,
+            (0..100).filter(|i| i % 3 != 0).map(|i| i * 2).sum::<u32>()
+        );
+
+        let mut ids: Vec<_> = (&u32s, &i16s)
+            .par_iter()
+            .filtered(|(&x, _)| x % 2 == 0)
+            .with_id()
+            .map(|(id, (&x, _))| (id, x))
+            .collect();
+        ids.sort_unstable_by_key(|&(_, x)| x);
+        assert_eq!(
+            ids,
+            (0..100u32)
+                .filter(|i| i % 3 != 0 && i % 2 == 0)
+                .map(|i| (entities[i as usize], i))
+                .collect::<Vec<_>>()
+        );
+    });
+}
@@ -1,3 +1,4 @@
+use shipyard::internal::iterators;
 use shipyard::prelude::*;
 
 #[test]
@@ -491,3 +492,73 @@ fn enumerate_filter_map_with_id() {
         assert!(iter.next().is_none());
     });
 }
+
+#[test]
+fn optional() {
+    let world = World::new::<(u32, i16, u64)>();
+
+    world.tight_pack::<(u32, i16)>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut i16, &mut u64), _, _>(
+        |(mut entities, mut u32s, mut i16s, mut u64s)| {
+            entities.add_entity((&mut u32s, &mut i16s), (0, 10));
+            entities.add_entity(&mut u32s, 1);
+            let entity = entities.add_entity((&mut u32s, &mut i16s), (2, 12));
+            entities.add_entity(&mut i16s, 13);
+            entities.add_entity((&mut u32s, &mut i16s), (4, 14));
+            entities.add_component(&mut u64s, 22, entity);
+        },
+    );
+
+    world.run::<(&u32, &i16, &u64), _, _>(|(u32s, i16s, u64s)| {
+        let mut iter = (&u32s, Optional(&i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (&0, Some(&10)));
+        assert_eq!(iter.next().unwrap(), (&2, Some(&12)));
+        assert_eq!(iter.next().unwrap(), (&4, Some(&14)));
+        assert_eq!(iter.next().unwrap(), (&1, None));
+        assert!(iter.next().is_none());
+
+        // the tight pack still limits the iteration
+        let mut iter = (&u32s, &i16s, Optional(&u64s)).iter();
+        assert_eq!(iter.next().unwrap(), (&0, &10, None));
+        assert_eq!(iter.next().unwrap(), (&2, &12, Some(&22)));
+        assert_eq!(iter.next().unwrap(), (&4, &14, None));
+        assert!(iter.next().is_none());
+    });
+}
+
+#[test]
+fn into_iterator() {
+    let world = World::new::<(u32, i16)>();
+
+    world.tight_pack::<(u32, i16)>();
+
+    let (key0, _, key2) = world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(
+        |(mut entities, mut u32s, mut i16s)| {
+            (
+                entities.add_entity((&mut u32s, &mut i16s), (0, 10)),
+                entities.add_entity(&mut u32s, 1),
+                entities.add_entity((&mut u32s, &mut i16s), (2, 12)),
+            )
+        },
+    );
+
+    world.run::<(&u32, &i16), _, _>(|(u32s, i16s)| {
+        if let iterators::Iter2::Tight(iter) = (&u32s, &i16s).iter() {
+            let iter = iter.with_id().into_iterator();
+            assert_eq!(iter.len(), 2);
+            assert_eq!(
+                iter.rev().collect::<Vec<_>>(),
+                vec![(key2, (&2, &12)), (key0, (&0, &10))]
+            );
+        } else {
+            panic!("not tight");
+        }
+
+        let mut iter = (&u32s, &i16s).iter();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next_back(), Some((&2, &12)));
+        assert_eq!(iter.next(), Some((&0, &10)));
+        assert!(iter.next_back().is_none());
+    });
+}
@@ -165,3 +165,62 @@ fn enumerate_filter_map_with_id() {
 
     assert_eq!(vec, vec![(0, key0, 10), (6, key2, 12)]);
 }
+
+#[test]
+fn into_iterator() {
+    let world = World::new::<(u32,)>();
+
+    let (key0, key1, key2) =
+        world.run::<(EntitiesMut, &mut u32), _, _>(|(mut entities, mut u32s)| {
+            (
+                entities.add_entity(&mut u32s, 0),
+                entities.add_entity(&mut u32s, 1),
+                entities.add_entity(&mut u32s, 2),
+            )
+        });
+
+    world.run::<&u32, _, _>(|u32s| {
+        let iter = (&u32s).iter().into_iterator();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.rev().copied().collect::<Vec<_>>(), vec![2, 1, 0]);
+
+        let mut iter = (&u32s).iter();
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+
+        assert_eq!((&u32s).iter().into_iterator().skip(1).sum::<u32>(), 3);
+        assert_eq!(
+            (&u32s)
+                .iter()
+                .with_id()
+                .into_iterator()
+                .rev()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            vec![key2, key1, key0]
+        );
+        assert_eq!(
+            (&u32s)
+                .iter()
+                .enumerate()
+                .into_iterator()
+                .rev()
+                .take(2)
+                .collect::<Vec<_>>(),
+            vec![(2, &2), (1, &1)]
+        );
+    });
+    world.run::<&mut u32, _, _>(|u32s| {
+        u32s.iter()
+            .into_iterator()
+            .zip(10..)
+            .for_each(|(x, y)| *x += y);
+    });
+    world.run::<&u32, _, _>(|u32s| {
+        assert_eq!(
+            (&u32s).iter().into_iterator().max_by_key(|&&x| x),
+            Some(&14)
+        );
+    });
+}
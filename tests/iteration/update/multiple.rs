@@ -791,3 +791,108 @@ fn enumerate_filter_map_with_id() {
         i16s.clear_modified();
     });
 }
+
+#[test]
+fn optional() {
+    let world = World::new::<(u32, i16)>();
+
+    world.update_pack::<u32>();
+    world.update_pack::<i16>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(|(mut entities, mut u32s, mut i16s)| {
+        entities.add_entity((&mut u32s, &mut i16s), (0, 10));
+        entities.add_entity(&mut u32s, 1);
+        entities.add_entity((&mut u32s, &mut i16s), (2, 12));
+        entities.add_entity(&mut i16s, 13);
+        entities.add_entity((&mut u32s, &mut i16s), (4, 14));
+
+        u32s.clear_inserted();
+        i16s.clear_inserted();
+    });
+
+    world.run::<(&u32, &mut i16), _, _>(|(u32s, mut i16s)| {
+        let mut iter = (&u32s, Optional(&mut i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (&0, Some(&mut 10)));
+        assert_eq!(iter.next().unwrap(), (&1, None));
+        assert_eq!(iter.next().unwrap(), (&2, Some(&mut 12)));
+        assert_eq!(iter.next().unwrap(), (&4, Some(&mut 14)));
+        assert!(iter.next().is_none());
+
+        // only components present are modified
+        let mut iter = i16s.modified().iter();
+        assert_eq!(iter.next().unwrap(), &10);
+        assert_eq!(iter.next().unwrap(), &12);
+        assert_eq!(iter.next().unwrap(), &14);
+        assert!(iter.next().is_none());
+    });
+}
+
+#[test]
+fn any_of() {
+    let world = World::new::<(u32, i16)>();
+
+    world.update_pack::<u32>();
+    world.update_pack::<i16>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(|(mut entities, mut u32s, mut i16s)| {
+        entities.add_entity((&mut u32s, &mut i16s), (0, 10));
+        entities.add_entity(&mut u32s, 1);
+        entities.add_entity((&mut u32s, &mut i16s), (2, 12));
+        entities.add_entity(&mut i16s, 13);
+
+        u32s.clear_inserted();
+        i16s.clear_inserted();
+    });
+
+    world.run::<(&mut u32, &mut i16), _, _>(|(mut u32s, mut i16s)| {
+        let mut iter = AnyOf((&u32s, &mut i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (Some(&0), Some(&mut 10)));
+        assert_eq!(iter.next().unwrap(), (Some(&1), None));
+        assert_eq!(iter.next().unwrap(), (Some(&2), Some(&mut 12)));
+        assert_eq!(iter.next().unwrap(), (None, Some(&mut 13)));
+        assert!(iter.next().is_none());
+
+        assert!(u32s.modified().iter().next().is_none());
+        assert_eq!(i16s.modified().len(), 3);
+
+        let mut iter = AnyOf((&mut u32s, &i16s)).iter();
+        assert_eq!(iter.next().unwrap(), (Some(&mut 0), Some(&10)));
+        assert_eq!(iter.next().unwrap(), (Some(&mut 1), None));
+        assert_eq!(iter.next().unwrap(), (Some(&mut 2), Some(&12)));
+        assert_eq!(iter.next().unwrap(), (None, Some(&13)));
+        assert!(iter.next().is_none());
+
+        assert_eq!(u32s.modified().len(), 3);
+    });
+}
+
+#[test]
+fn rev() {
+    let world = World::new::<(u32, i16)>();
+
+    world.update_pack::<u32>();
+    world.update_pack::<i16>();
+
+    world.run::<(EntitiesMut, &mut u32, &mut i16), _, _>(|(mut entities, mut u32s, mut i16s)| {
+        entities.add_entity((&mut u32s, &mut i16s), (0, 10));
+        entities.add_entity(&mut u32s, 1);
+        entities.add_entity((&mut u32s, &mut i16s), (2, 12));
+        entities.add_entity((&mut u32s, &mut i16s), (3, 13));
+        entities.add_entity((&mut u32s, &mut i16s), (4, 14));
+
+        u32s.clear_inserted();
+        i16s.clear_inserted();
+    });
+
+    world.run::<(&mut u32, &mut i16), _, _>(|(mut u32s, mut i16s)| {
+        let rev = (&mut u32s, &mut i16s)
+            .iter()
+            .into_iterator()
+            .rev()
+            .map(|(x, y)| (*x, *y))
+            .collect::<Vec<_>>();
+        assert_eq!(rev, vec![(4, 14), (3, 13), (2, 12), (0, 10)]);
+        assert_eq!(u32s.modified().len(), 4);
+        assert_eq!(i16s.modified().len(), 4);
+    });
+}
@@ -193,3 +193,124 @@ fn enumerate_filter_map_with_id() {
     assert_eq!(vec, vec![(0, key0, 10), (6, key2, 12)]);
     assert_eq!(modified, vec![10, 12]);
 }
+
+#[test]
+fn into_iterator() {
+    let world = World::new::<(u32,)>();
+
+    world.update_pack::<u32>();
+
+    world.run::<(EntitiesMut, &mut u32), _, _>(|(mut entities, mut u32s)| {
+        entities.add_entity(&mut u32s, 0);
+        entities.add_entity(&mut u32s, 1);
+        entities.add_entity(&mut u32s, 2);
+        u32s.clear_inserted();
+    });
+
+    world.run::<&mut u32, _, _>(|mut u32s| {
+        let iter = (&mut u32s).iter().into_iterator();
+        assert_eq!(iter.len(), 3);
+        iter.rev().take(2).for_each(|x| *x += 10);
+
+        // going backward flags all remaining components
+        let mut modified = u32s.modified().iter().into_iterator().collect::<Vec<_>>();
+        modified.sort();
+        assert_eq!(modified, vec![&0, &11, &12]);
+    });
+}
+
+#[test]
+fn rev() {
+    let world = World::new::<(u32,)>();
+
+    world.update_pack::<u32>();
+
+    world.run::<(EntitiesMut, &mut u32), _, _>(|(mut entities, mut u32s)| {
+        for i in 0..5 {
+            entities.add_entity(&mut u32s, i);
+        }
+        u32s.clear_inserted();
+    });
+
+    world.run::<&mut u32, _, _>(|mut u32s| {
+        let rev = (&mut u32s)
+            .iter()
+            .into_iterator()
+            .rev()
+            .map(|x| *x)
+            .collect::<Vec<_>>();
+        assert_eq!(rev, vec![4, 3, 2, 1, 0]);
+        assert_eq!(u32s.modified().len(), 5);
+
+        u32s.clear_modified();
+
+        let mut iter = (&mut u32s).iter();
+        assert_eq!(iter.next().map(|x| *x), Some(0));
+        assert_eq!(iter.next_back().map(|x| *x), Some(4));
+        assert_eq!(
+            iter.into_iterator().rev().map(|x| *x).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert_eq!(u32s.modified().len(), 5);
+    });
+}
+
+#[test]
+fn chunk() {
+    let world = World::new::<(u32,)>();
+
+    world.update_pack::<u32>();
+
+    world.run::<(EntitiesMut, &mut u32), _, _>(|(mut entities, mut u32s)| {
+        entities.add_entity(&mut u32s, 0);
+        entities.add_entity(&mut u32s, 1);
+        entities.add_entity(&mut u32s, 2);
+        entities.add_entity(&mut u32s, 3);
+        entities.add_entity(&mut u32s, 4);
+
+        let mut iter = u32s.inserted().iter().into_chunk(2).ok().unwrap();
+        assert_eq!(iter.next(), Some(&[0, 1][..]));
+        assert_eq!(iter.next(), Some(&[2, 3][..]));
+        assert_eq!(iter.next(), Some(&[4][..]));
+        assert!(iter.next().is_none());
+
+        u32s.clear_inserted();
+    });
+
+    world.run::<&mut u32, _, _>(|mut u32s| {
+        assert!(u32s.modified().is_empty());
+
+        let mut iter = (&mut u32s).iter();
+        iter.next();
+        iter.next();
+        let mut iter = iter.into_chunk_exact(2).ok().unwrap();
+        iter.next().unwrap().iter_mut().for_each(|x| *x += 10);
+        iter.remainder().iter_mut().for_each(|x| *x += 10);
+
+        assert_eq!(u32s.modified().len(), 5);
+        assert_eq!(
+            u32s.modified()
+                .iter()
+                .into_iterator()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![0, 1, 12, 13, 14]
+        );
+
+        u32s.modified_mut()
+            .iter()
+            .into_chunk(3)
+            .ok()
+            .unwrap()
+            .for_each(|slice| slice.iter_mut().for_each(|x| *x += 1));
+        assert_eq!(u32s.modified().len(), 5);
+        assert_eq!(
+            u32s.modified()
+                .iter()
+                .into_iterator()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 2, 13, 14, 15]
+        );
+    });
+}
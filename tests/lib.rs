@@ -855,7 +855,6 @@ fn systems() {
         assert_eq!(iter.next(), None);
     });
 }
-/*
 #[cfg(feature = "parallel")]
 #[test]
 fn simple_parallel_sum() {
@@ -872,7 +871,7 @@ fn simple_parallel_sum() {
 
     world.run::<(&mut usize, ThreadPool), _, _>(|(usizes, thread_pool)| {
         thread_pool.install(|| {
-            let sum: usize = (&usizes,).par_iter().cloned().sum();
+            let sum: usize = (&usizes).par_iter().cloned().sum();
             assert_eq!(sum, 4);
         });
     });
@@ -979,7 +978,6 @@ fn loose_parallel_iterator() {
         assert_eq!(iter.next(), None);
     });
 }
-*/
 #[cfg(feature = "parallel")]
 #[test]
 fn two_workloads() {
@@ -1003,7 +1001,7 @@ fn two_workloads() {
 #[cfg(feature = "parallel")]
 #[test]
 #[should_panic(
-    expected = "Result::unwrap()` on an `Err` value: Cannot mutably borrow \"usize\" storage while it's already borrowed."
+    expected = "failed: Cannot mutably borrow \"usize\" storage while it's already borrowed."
 )]
 fn two_bad_workloads() {
     struct System1;
@@ -1397,7 +1395,6 @@ fn update_pack() {
         assert_eq!(iter.next(), None);
     });
 }
-/*
 #[cfg(feature = "parallel")]
 #[test]
 fn par_update_pack() {
@@ -1433,7 +1430,6 @@ fn par_update_pack() {
         assert_eq!(iter.next(), None);
     });
 }
-*/
 #[test]
 fn simple_with_id() {
     let world = World::new::<(usize, u32)>();
@@ -1524,7 +1520,6 @@ fn multiple_update_pack() {
         assert_eq!(iter.next(), None);
     });
 }
-/*
 #[cfg(feature = "parallel")]
 #[test]
 fn par_multiple_update_pack() {
@@ -1570,11 +1565,11 @@ fn par_multiple_update_pack() {
             panic!("not packed");
         }
 
-        let mut modified: Vec<_> = u32s.modified().iter().collect();
+        let mut modified: Vec<_> = u32s.modified().iter().into_iterator().collect();
         modified.sort_unstable();
         assert_eq!(modified, vec![&1, &5, &9]);
 
-        let mut iter: Vec<_> = (&u32s).iter().collect();
+        let mut iter: Vec<_> = (&u32s).iter().into_iterator().collect();
         iter.sort_unstable();
         assert_eq!(iter, vec![&1, &5, &7, &9]);
     });
@@ -1606,16 +1601,15 @@ fn par_update_filter() {
         let mut iter = usizes.inserted().iter();
         assert_eq!(iter.next(), None);
 
-        let mut modified: Vec<_> = usizes.modified().iter().collect();
+        let mut modified: Vec<_> = usizes.modified().iter().into_iterator().collect();
         modified.sort_unstable();
         assert_eq!(modified, vec![&1, &3]);
 
-        let mut iter: Vec<_> = (&usizes).iter().collect();
+        let mut iter: Vec<_> = (&usizes).iter().into_iterator().collect();
         iter.sort_unstable();
         assert_eq!(iter, vec![&1, &1, &3, &3]);
     });
 }
-*/
 #[test]
 fn filter_with_id() {
     let world = World::new::<(usize,)>();
@@ -1634,7 +1628,6 @@ fn filter_with_id() {
         assert!(iter.next() == None);
     });
 }
-/*
 #[cfg(feature = "parallel")]
 #[test]
 fn par_filter_with_id() {
@@ -1659,7 +1652,6 @@ fn par_filter_with_id() {
         assert!(result == vec![(entity1, &mut 2), (entity2, &mut 4)]);
     });
 }
-*/
 #[test]
 fn with_id_filter() {
     let world = World::new::<(usize,)>();
@@ -1678,7 +1670,6 @@ fn with_id_filter() {
         assert!(iter.next() == None);
     });
 }
-/*
 #[cfg(feature = "parallel")]
 #[test]
 fn par_with_id_filter() {
@@ -1703,7 +1694,6 @@ fn par_with_id_filter() {
         assert!(result == vec![(entity1, &mut 2), (entity2, &mut 4)]);
     });
 }
-*/
 #[test]
 fn unique_storage() {
     let world = World::default();
@@ -1832,3 +1822,1195 @@ fn unique_storage_pack() {
         >()))
     );
 }
+
+#[test]
+fn commands() {
+    struct Spawner;
+    impl<'a> System<'a> for Spawner {
+        type Data = (Commands, &'a u32);
+        fn run((mut commands, u32s): <Self::Data as SystemData>::View) {
+            u32s.iter().with_id().for_each(|(id, &x)| {
+                commands.add_entity((x as usize, x as i16));
+                commands.add_component((x as usize,), id);
+            });
+        }
+    }
+    struct Cleaner;
+    impl<'a> System<'a> for Cleaner {
+        type Data = (Commands, &'a i16);
+        fn run((mut commands, i16s): <Self::Data as SystemData>::View) {
+            i16s.iter().with_id().for_each(|(id, _)| {
+                commands.delete(id);
+            });
+        }
+    }
+    struct Checker;
+    impl<'a> System<'a> for Checker {
+        type Data = (&'a usize, &'a u32, &'a i16);
+        fn run((usizes, u32s, i16s): <Self::Data as SystemData>::View) {
+            assert_eq!(usizes.len(), 4);
+            assert_eq!(u32s.len(), 2);
+            assert_eq!(i16s.len(), 2);
+            assert_eq!((&usizes, &u32s).iter().count(), 2);
+        }
+    }
+
+    let world = World::new::<(usize, u32, i16)>();
+    world.run::<(EntitiesMut, &mut u32), _, _>(|(mut entities, mut u32s)| {
+        entities.add_entity((&mut u32s,), (0,));
+        entities.add_entity((&mut u32s,), (1,));
+    });
+
+    world.add_workload(
+        "Commands",
+        (
+            Spawner,
+            Checker.after::<Spawner>(),
+            Cleaner.after::<Checker>(),
+        ),
+    );
+    world.run_default();
+
+    world.run::<(&usize, &u32, &i16), _, _>(|(usizes, u32s, i16s)| {
+        assert_eq!(usizes.len(), 2);
+        assert_eq!(u32s.len(), 2);
+        assert_eq!(i16s.len(), 0);
+    });
+
+    let entity = world.run::<(EntitiesMut, &mut u32), _, _>(|(mut entities, mut u32s)| {
+        entities.add_entity((&mut u32s,), (2,))
+    });
+    world.run::<Commands, _, _>(|mut commands| {
+        commands.remove::<(u32,)>(entity);
+    });
+    world.run::<&u32, _, _>(|u32s| assert_eq!(u32s.len(), 3));
+    world.apply_commands();
+    world.run::<&u32, _, _>(|u32s| assert_eq!(u32s.len(), 2));
+}
+
+#[test]
+fn commands_reserved_entity() {
+    let world = World::new::<(usize, u32)>();
+
+    let entity = world.run::<Commands, _, _>(|mut commands| {
+        let entity = commands.add_entity((0usize,));
+        commands.add_component((1u32,), entity);
+        entity
+    });
+    world.run::<Entities, _, _>(|entities| assert!(entities.is_alive(entity)));
+    world.apply_commands();
+
+    world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+        assert_eq!((&usizes, &u32s).get(entity), Some((&0, &1)));
+    });
+}
+
+#[test]
+fn commands_errors() {
+    let world = World::new::<(usize, u32)>();
+    world.tight_pack::<(usize, u32)>();
+
+    let entity = world.run::<Commands, _, _>(|mut commands| {
+        commands.add_entity((0u8,));
+        commands.add_entity((1usize, 2u32))
+    });
+    assert_eq!(
+        world.try_apply_commands(),
+        Err(shipyard::error::ApplyCommands::GetStorage(
+            shipyard::error::GetStorage::MissingComponent(std::any::type_name::<u8>())
+        ))
+    );
+    // the other commands are still applied
+    world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+        assert_eq!((&usizes, &u32s).get(entity), Some((&1, &2)));
+    });
+
+    world.run::<Commands, _, _>(|mut commands| {
+        commands.remove::<(usize,)>(entity);
+    });
+    assert_eq!(
+        world.try_apply_commands(),
+        Err(shipyard::error::ApplyCommands::Remove(
+            shipyard::error::Remove::MissingPackStorage(std::any::TypeId::of::<usize>())
+        ))
+    );
+}
+
+#[test]
+fn workload_builder() {
+    struct Add;
+    impl<'a> System<'a> for Add {
+        type Data = (&'a mut usize, &'a u32);
+        fn run((usizes, u32s): <Self::Data as SystemData>::View) {
+            (usizes, u32s).iter().for_each(|(x, &y)| *x += y as usize);
+        }
+    }
+
+    let world = World::new::<(usize, u32)>();
+    world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+        |(mut entities, mut usizes, mut u32s)| {
+            entities.add_entity((&mut usizes, &mut u32s), (0, 1));
+            entities.add_entity((&mut usizes, &mut u32s), (2, 3));
+        },
+    );
+
+    let mut builder = WorkloadBuilder::default();
+    for _ in 0..15 {
+        builder = builder.with_system(Add);
+    }
+    let builder = builder
+        .with_fn::<&usize, _>(|usizes| {
+            let mut iter = usizes.iter();
+            assert_eq!(iter.next(), Some(&15));
+            assert_eq!(iter.next(), Some(&47));
+            assert_eq!(iter.next(), None);
+        })
+        .with_system(
+            WorkloadSystem::from_fn::<&mut u32, _>(|u32s| {
+                u32s.iter().for_each(|x| *x = 0);
+            })
+            .label("reset"),
+        )
+        .with_fn::<&u32, _>(|u32s| u32s.iter().for_each(|&x| assert_eq!(x, 0)));
+    assert_eq!(builder.len(), 18);
+
+    world.add_workload("Builder", builder);
+    world.run_default();
+
+    world.run::<(&usize, &u32), _, _>(|(usizes, u32s)| {
+        assert_eq!(usizes.len(), 2);
+        assert_eq!(u32s.len(), 2);
+    });
+}
+
+#[test]
+fn fallible_systems() {
+    #[derive(Debug)]
+    struct Failure;
+    impl std::fmt::Display for Failure {
+        fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+            fmt.write_str("Failure")
+        }
+    }
+    impl std::error::Error for Failure {}
+
+    struct Fail;
+    impl<'a> TrySystem<'a> for Fail {
+        type Data = &'a usize;
+        type Error = Failure;
+        fn try_run(_: <Self::Data as SystemData>::View) -> Result<(), Failure> {
+            Err(Failure)
+        }
+    }
+    struct Increment;
+    impl<'a> System<'a> for Increment {
+        type Data = Unique<&'a mut u32>;
+        fn run(x: <Self::Data as SystemData>::View) {
+            *x += 1;
+        }
+    }
+    struct MissingStorage;
+    impl<'a> System<'a> for MissingStorage {
+        type Data = &'a u8;
+        fn run(_: <Self::Data as SystemData>::View) {}
+    }
+
+    let world = World::new::<(usize,)>();
+    world.register_unique(0u32);
+
+    world.add_workload(
+        "Fail",
+        WorkloadBuilder::default()
+            .with_try_system(Fail)
+            .with_system(Increment)
+            .with_system(Increment),
+    );
+    world.add_workload("Missing", MissingStorage);
+
+    match world.try_run_workload("Fail") {
+        Err(shipyard::error::RunWorkload::System((name, error))) => {
+            assert!(name.ends_with("Fail"));
+            assert!(error.is::<Failure>());
+        }
+        _ => panic!("the workload should fail"),
+    }
+    // the second Increment is in the next batch and doesn't run
+    world.run::<Unique<&u32>, _, _>(|x| assert_eq!(*x, 1));
+
+    let error = world.try_run_workload("Fail").unwrap_err();
+    assert_eq!(error.clone(), error);
+    assert_ne!(error, shipyard::error::RunWorkload::MissingWorkload);
+
+    match world.try_run_workload("Missing") {
+        Err(shipyard::error::RunWorkload::System((name, error))) => {
+            assert!(name.ends_with("MissingStorage"));
+            assert_eq!(
+                error.downcast_ref::<shipyard::error::GetStorage>(),
+                Some(&shipyard::error::GetStorage::MissingComponent(
+                    std::any::type_name::<u8>()
+                ))
+            );
+        }
+        _ => panic!("the workload should fail"),
+    }
+}
+
+#[test]
+fn track_changes() {
+    let world = World::new::<(usize, u32)>();
+    world.tight_pack::<(usize, u32)>();
+    world.track_changes::<usize>();
+    world.register_unique(Vec::<(usize, usize)>::new());
+
+    world.add_workload(
+        "Changes",
+        WorkloadSystem::from_fn::<(&usize, Unique<&mut Vec<(usize, usize)>>), _>(
+            |(usizes, counts)| {
+                counts.push((usizes.added().count(), usizes.changed().count()));
+            },
+        ),
+    );
+
+    let (entity0, entity1) = world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+        |(mut entities, mut usizes, mut u32s)| {
+            (
+                entities.add_entity((&mut usizes, &mut u32s), (0, 0)),
+                entities.add_entity((&mut usizes, &mut u32s), (1, 1)),
+            )
+        },
+    );
+    world.run_default();
+    world.run_default();
+
+    world.run::<&mut usize, _, _>(|mut usizes| {
+        *(&mut usizes).get(entity1).unwrap() += 1;
+    });
+    world.run_default();
+
+    world.run::<(&mut usize, &mut u32), _, _>(|(mut usizes, mut u32s)| {
+        (&mut usizes, &mut u32s).iter().for_each(|(x, _)| *x += 1);
+    });
+    world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+        |(mut entities, mut usizes, mut u32s)| {
+            entities.add_entity((&mut usizes, &mut u32s), (2, 2));
+        },
+    );
+    world.run_default();
+
+    world.run::<(&usize, Unique<&Vec<(usize, usize)>>), _, _>(|(usizes, counts)| {
+        assert_eq!(&**counts, &[(2, 2), (0, 0), (0, 1), (1, 3)]);
+        // outside of workloads all changes since tracking started are visible
+        assert!(usizes.is_added(entity0));
+        assert!(usizes.is_changed(entity1));
+    });
+}
+
+#[test]
+fn added_changed_filters() {
+    let world = World::new::<(usize, u32)>();
+    world.tight_pack::<(usize, u32)>();
+    world.track_changes::<u32>();
+    world.register_unique(Vec::<(Vec<usize>, Vec<usize>)>::new());
+
+    world.add_workload(
+        "Filters",
+        WorkloadSystem::from_fn::<(&usize, &u32, Unique<&mut Vec<(Vec<usize>, Vec<usize>)>>), _>(
+            |(usizes, u32s, seen)| {
+                let mut added = Vec::new();
+                (&usizes, Added(&u32s))
+                    .iter()
+                    .for_each(|(x, _)| added.push(*x));
+                let mut changed = Vec::new();
+                (&usizes, Changed(&u32s))
+                    .iter()
+                    .for_each(|(x, _)| changed.push(*x));
+                seen.push((added, changed));
+            },
+        ),
+    );
+
+    let entity0 = world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+        |(mut entities, mut usizes, mut u32s)| {
+            let entity0 = entities.add_entity((&mut usizes, &mut u32s), (0, 0));
+            entities.add_entity((&mut usizes, &mut u32s), (1, 1));
+            entities.add_entity((&mut usizes,), (2,));
+            entity0
+        },
+    );
+    world.run_default();
+    world.run_default();
+
+    world.run::<&mut u32, _, _>(|mut u32s| {
+        *(&mut u32s).get(entity0).unwrap() += 1;
+    });
+    world.run_default();
+
+    world.run::<(&usize, &mut u32), _, _>(|(usizes, u32s)| {
+        // filters can be nested and work with mutable views
+        let mut iter = (&usizes, Optional(Added(&u32s))).iter();
+        assert_eq!(iter.next(), Some((&0, Some(&1))));
+        assert_eq!(iter.next(), Some((&1, Some(&1))));
+        assert_eq!(iter.next(), Some((&2, None)));
+        assert_eq!(iter.next(), None);
+        // filters alone don't drive iteration
+        assert_eq!(Changed(&u32s).iter().count(), 0);
+    });
+
+    world.run::<Unique<&Vec<(Vec<usize>, Vec<usize>)>>, _, _>(|seen| {
+        assert_eq!(
+            &**seen,
+            &[
+                (vec![0, 1], vec![0, 1]),
+                (vec![], vec![]),
+                (vec![], vec![0]),
+            ]
+        );
+    });
+}
+
+#[test]
+fn update_pack_cursors() {
+    #[derive(Default)]
+    struct Seen {
+        added: usize,
+        changed: usize,
+        removed: usize,
+    }
+
+    let world = World::new::<(usize,)>();
+    world.update_pack::<usize>();
+    world.register_unique(Seen::default());
+    world.register_unique(0u32);
+
+    let entities = world.run::<(EntitiesMut, &mut usize), _, _>(|(mut entities, mut usizes)| {
+        (0..3)
+            .map(|i| entities.add_entity((&mut usizes,), (i,)))
+            .collect::<Vec<_>>()
+    });
+
+    world.add_workload(
+        "Readers",
+        WorkloadBuilder::default()
+            // this reader clears the update pack's shared window every time it runs
+            .with_fn::<(&mut usize, Unique<&mut u32>), _>(|(mut usizes, count)| {
+                *count += usizes.inserted().len() as u32;
+                usizes.clear_inserted();
+            })
+            .with_fn::<(&usize, Unique<&mut Seen>), _>(|(usizes, seen)| {
+                seen.added += usizes.added().count();
+                seen.changed += usizes.changed().count();
+                seen.removed += usizes.removed().count();
+            }),
+    );
+    world.add_workload(
+        "Other",
+        WorkloadSystem::from_fn::<(&usize, Unique<&mut Seen>), _>(|(usizes, seen)| {
+            seen.removed += 10 * usizes.removed().count();
+        }),
+    );
+
+    world.run_workload("Readers");
+    world.run::<(Unique<&u32>, Unique<&Seen>), _, _>(|(count, seen)| {
+        assert_eq!(*count, 3);
+        assert_eq!((seen.added, seen.changed, seen.removed), (3, 3, 0));
+    });
+
+    world.run::<&mut usize, _, _>(|mut usizes| {
+        *(&mut usizes).get(entities[1]).unwrap() += 1;
+    });
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        all_storages.delete(entities[2]);
+    });
+    world.run_workload("Readers");
+    world.run_workload("Readers");
+    world.run::<(Unique<&u32>, Unique<&Seen>), _, _>(|(count, seen)| {
+        assert_eq!(*count, 3);
+        assert_eq!((seen.added, seen.changed, seen.removed), (3, 4, 1));
+    });
+
+    // "Other" never ran, the removal is still visible to it
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        all_storages.delete(entities[0]);
+    });
+    world.run_workload("Other");
+    world.run::<Unique<&Seen>, _, _>(|seen| assert_eq!(seen.removed, 21));
+}
+
+#[test]
+fn hierarchy() {
+    let world = World::new::<(Parent, Child, usize)>();
+
+    let (root1, root2, e1, e2, e3) = world.run::<(EntitiesMut, &mut Parent, &mut Child), _, _>(
+        |(mut entities, mut parents, mut children)| {
+            let root1 = entities.add_entity((), ());
+            let root2 = entities.add_entity((), ());
+            let e1 = entities.add_entity((), ());
+            let e2 = entities.add_entity((), ());
+            let e3 = entities.add_entity((), ());
+
+            (&entities, &mut parents, &mut children).attach(e1, root1);
+            (&entities, &mut parents, &mut children).attach(e2, root1);
+            (&entities, &mut parents, &mut children).attach(e3, e1);
+
+            assert_eq!(
+                (&entities, &mut parents, &mut children).try_attach(root1, e3),
+                Err(shipyard::error::Attach::Cycle)
+            );
+            assert_eq!(
+                (&entities, &mut parents, &mut children).try_attach(e1, e1),
+                Err(shipyard::error::Attach::Cycle)
+            );
+
+            // reparent e1 and its subtree
+            (&entities, &mut parents, &mut children).attach(e1, root2);
+            assert_eq!(
+                (&parents, &children).children(root1).collect::<Vec<_>>(),
+                vec![e2]
+            );
+            assert_eq!(
+                (&parents, &children).ancestors(e3).collect::<Vec<_>>(),
+                vec![e1, root2]
+            );
+
+            (&entities, &mut parents, &mut children).detach(e2);
+            assert!(parents.get(root1).is_none());
+            assert!(children.get(e2).is_none());
+
+            (&entities, &mut parents, &mut children).attach(e2, root2);
+            assert_eq!(parents.get(root2).unwrap().num_children(), 2);
+
+            (root1, root2, e1, e2, e3)
+        },
+    );
+
+    // deleting e1 turns e3 into a root and keeps root2's children consistent
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        assert!(all_storages.delete(e1));
+    });
+    world.run::<(&Parent, &Child), _, _>(|(parents, children)| {
+        assert_eq!(
+            (&parents, &children).children(root2).collect::<Vec<_>>(),
+            vec![e2]
+        );
+        assert_eq!((&parents, &children).parent(e3), None);
+        assert_eq!((&parents, &children).parent(e2), Some(root2));
+    });
+
+    // a link to a parent without Parent component is not followed
+    world.run::<(Entities, &mut Parent, &mut Child), _, _>(
+        |(entities, mut parents, mut children)| {
+            Remove::<(Parent,)>::remove((&mut parents,), root2);
+            assert_eq!((&parents, &children).parent(e2), None);
+            assert_eq!((&parents, &children).ancestors(e2).count(), 0);
+
+            (&entities, &mut parents, &mut children).attach(e2, root2);
+            assert_eq!((&parents, &children).parent(e2), Some(root2));
+        },
+    );
+
+    let e4 = world.run::<(EntitiesMut, &mut Parent, &mut Child, &mut usize), _, _>(
+        |(mut entities, mut parents, mut children, mut usizes)| {
+            let e4 = entities.add_entity((&mut usizes,), (0,));
+            (&entities, &mut parents, &mut children).attach(e4, e2);
+            entities.add_entity((&mut usizes,), (1,));
+            e4
+        },
+    );
+
+    // dead entities can't be attached
+    let dead = world.run::<EntitiesMut, _, _>(|mut entities| entities.add_entity((), ()));
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        assert!(all_storages.delete(dead));
+    });
+    world.run::<(Entities, &mut Parent, &mut Child), _, _>(
+        |(entities, mut parents, mut children)| {
+            assert_eq!(
+                (&entities, &mut parents, &mut children).try_attach(e4, dead),
+                Err(shipyard::error::Attach::ParentIsNotAlive)
+            );
+            assert_eq!(
+                (&entities, &mut parents, &mut children).try_attach(dead, e4),
+                Err(shipyard::error::Attach::EntityIsNotAlive)
+            );
+            assert!(parents.get(dead).is_none());
+            assert_eq!((&parents, &children).parent(e4), Some(e2));
+        },
+    );
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        assert!(all_storages.delete_recursive(root2));
+        assert!(!all_storages.delete_recursive(root2));
+        assert!(all_storages.delete(root1));
+    });
+    world.run::<(&Parent, &Child, &usize), _, _>(|(parents, children, usizes)| {
+        assert!(parents.is_empty());
+        assert!(children.is_empty());
+        assert_eq!((&parents, &children).descendants(e3).count(), 0);
+        assert_eq!(usizes.get(e4), None);
+        assert_eq!(usizes.len(), 1);
+    });
+}
+
+#[test]
+fn events() {
+    let world = World::default();
+    world.register_events::<u32>();
+    world.register_unique(0usize);
+    world.register_unique(0u64);
+
+    world.add_workload(
+        "Events",
+        WorkloadBuilder::default()
+            .with_fn::<Events<&mut u32>, _>(|mut events| {
+                events.send(1);
+                events.extend(vec![2]);
+            })
+            .with_fn::<(Events<&u32>, Unique<&mut usize>), _>(|(events, sum)| {
+                *sum += events.iter().sum::<u32>() as usize;
+            })
+            .with_fn::<(Events<&u32>, Unique<&mut u64>), _>(|(events, sum)| {
+                *sum += events.iter().sum::<u32>() as u64;
+            }),
+    );
+
+    // writers are serialized with readers, readers run in parallel
+    let info = world.workload_info("Events");
+    assert_eq!(info.batches.len(), 2);
+    assert_eq!(info.batches[0].systems.len(), 1);
+    assert_eq!(info.batches[1].systems.len(), 2);
+
+    // events become visible after the writer's batch
+    world.run_default();
+    world.run::<(Unique<&usize>, Unique<&u64>), _, _>(|(a, b)| {
+        assert_eq!((*a, *b), (3, 3));
+    });
+
+    // events sent with `World::run` are visible once commands are applied
+    world.run::<Events<&mut u32>, _, _>(|mut events| events.send(10));
+    world.run::<Events<&u32>, _, _>(|events| {
+        assert_eq!(events.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    });
+    // both readers read 1 and 2, they're dropped when new events become visible
+    world.apply_commands();
+    world.run::<Events<&u32>, _, _>(|events| {
+        assert_eq!(events.iter().copied().collect::<Vec<_>>(), vec![10]);
+    });
+
+    world.run_default();
+    world.run::<(Unique<&usize>, Unique<&u64>), _, _>(|(a, b)| {
+        assert_eq!((*a, *b), (16, 16));
+    });
+    world.run::<Events<&u32>, _, _>(|events| {
+        assert_eq!(events.iter().copied().collect::<Vec<_>>(), vec![10, 1, 2]);
+    });
+}
+
+#[test]
+fn events_reader_error() {
+    let world = World::default();
+    world.register_events::<u32>();
+    world.register_unique(0usize);
+
+    world.add_workload(
+        "Read",
+        WorkloadSystem::from_fn::<(Unique<&mut usize>, Events<&u32>), _>(|(sum, events)| {
+            *sum += events.iter().sum::<u32>() as usize;
+        }),
+    );
+
+    world.run::<Events<&mut u32>, _, _>(|mut events| events.send(1));
+    world.apply_commands();
+    world.run_workload("Read");
+
+    // the reader fails to borrow before reaching the channel
+    world.run::<Unique<&mut usize>, _, _>(|_| {
+        assert!(world.try_run_workload("Read").is_err());
+    });
+    world.run_workload("Read");
+
+    world.run::<Events<&mut u32>, _, _>(|mut events| events.send(2));
+    world.apply_commands();
+    world.run_workload("Read");
+    world.run::<Unique<&usize>, _, _>(|sum| assert_eq!(*sum, 3));
+
+    // the reader keeps a single cursor, events it read are dropped
+    world.run::<Events<&mut u32>, _, _>(|mut events| events.send(3));
+    world.apply_commands();
+    world.run::<Events<&u32>, _, _>(|events| {
+        assert_eq!(events.iter().copied().collect::<Vec<_>>(), vec![3]);
+    });
+}
+
+#[test]
+fn entity_builder() {
+    use iterators::Iter2;
+
+    let world = World::new::<(usize, u32)>();
+    world.tight_pack::<(usize, u32)>();
+
+    let entity0 = world.entity_builder().with(0usize).with(1u32).build();
+    let entity1 = world.entity_builder().with(2usize).build();
+    // i16 was never registered
+    let entity2 = world.entity_builder().with(3u32).with(4i16).build();
+    let entity3 = world.entity_builder().build();
+
+    world.run::<(&usize, &u32, &i16), _, _>(|(usizes, u32s, i16s)| {
+        assert_eq!((&usizes, &u32s).get(entity0), Some((&0, &1)));
+        assert_eq!(usizes.get(entity1), Some(&2));
+        assert_eq!((&u32s, &i16s).get(entity2), Some((&3, &4)));
+        assert!(usizes.get(entity3).is_none());
+
+        if let Iter2::Tight(mut iter) = (&usizes, &u32s).iter() {
+            assert_eq!(iter.next(), Some((&0, &1)));
+            assert_eq!(iter.next(), None);
+        } else {
+            panic!("not packed");
+        }
+    });
+
+    world.register::<u64>();
+    world.update_pack::<u64>();
+    world.entity_builder().with(5u64).build();
+    world.run::<&u64, _, _>(|u64s| {
+        assert_eq!(u64s.inserted().len(), 1);
+    });
+
+    let entities_len = world.run::<Entities, _, _>(|entities| entities.len());
+    assert_eq!(
+        world
+            .entity_builder()
+            .with(6u64)
+            .with(7usize)
+            .with(8u64)
+            .try_build(),
+        Err(shipyard::error::BuildEntity::DuplicateComponent("u64"))
+    );
+    world.run::<(Entities, &u64), _, _>(|(entities, u64s)| {
+        assert_eq!(entities.len(), entities_len);
+        assert_eq!(u64s.len(), 1);
+    });
+}
+
+#[test]
+fn bulk_add_entity() {
+    use iterators::Iter2;
+
+    let world = World::new::<(usize, u32, u64)>();
+    world.tight_pack::<(usize, u32)>();
+    world.update_pack::<u64>();
+
+    let entity0 = world.run::<(EntitiesMut, &mut usize), _, _>(|(mut entities, mut usizes)| {
+        entities.add_entity(&mut usizes, 10)
+    });
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        assert!(all_storages.delete(entity0));
+    });
+
+    let u64_entities = world.run::<(EntitiesMut, &mut usize, &mut u32, &mut u64), _, _>(
+        |(mut entities, mut usizes, mut u32s, mut u64s)| {
+            // the shortest column wins
+            let new_entities =
+                entities.bulk_add_entity((&mut usizes, &mut u32s), (vec![0, 1, 2], 0..2));
+            assert_eq!(new_entities.len(), 2);
+            assert_ne!(new_entities[0], entity0);
+            assert_eq!(usizes.get(entity0), None);
+            assert_eq!((&usizes, &u32s).get(new_entities[0]), Some((&0, &0)));
+            assert_eq!((&usizes, &u32s).get(new_entities[1]), Some((&1, &1)));
+
+            if let Iter2::Tight(iter) = (&usizes, &u32s).iter() {
+                assert_eq!(iter.count(), 2);
+            } else {
+                panic!("not packed");
+            }
+
+            let new_entities = entities.bulk_add_entity(&mut u64s, std::iter::repeat(5).take(3));
+            assert_eq!(new_entities.len(), 3);
+            assert_eq!(u64s.inserted().len(), 3);
+            assert!(new_entities
+                .iter()
+                .all(|&entity| u64s.get(entity) == Some(&5)));
+            new_entities
+        },
+    );
+
+    // components already stored keep their pack and window
+    world.track_changes::<u32>();
+    world.run::<(EntitiesMut, &mut usize, &mut u32, &mut u64), _, _>(
+        |(mut entities, mut usizes, mut u32s, mut u64s)| {
+            let lone = entities.add_entity(&mut usizes, 20);
+            u64s.clear_inserted();
+            let modified = u64_entities[1];
+            (&mut u64s)
+                .iter()
+                .with_id()
+                .filter(|(id, _)| *id == modified)
+                .for_each(|(_, x)| *x += 1);
+
+            let new_entities = entities.bulk_add_entity(
+                (&mut usizes, &mut u32s),
+                (30..33, (0..10).filter(|x| x % 3 == 0)),
+            );
+            assert_eq!(new_entities.len(), 3);
+            assert_eq!(usizes.get(lone), Some(&20));
+            for (i, &entity) in new_entities.iter().enumerate() {
+                assert_eq!(
+                    (&usizes, &u32s).get(entity),
+                    Some((&(30 + i), &(i as u32 * 3)))
+                );
+            }
+            if let Iter2::Tight(iter) = (&usizes, &u32s).iter() {
+                assert_eq!(iter.count(), 5);
+            } else {
+                panic!("not packed");
+            }
+            assert_eq!(u32s.added().collect::<Vec<_>>(), new_entities);
+
+            let new_entities = entities.bulk_add_entity(&mut u64s, 40..43);
+            for (i, &entity) in new_entities.iter().enumerate() {
+                assert_eq!(u64s.get(entity), Some(&(40 + i as u64)));
+            }
+            assert_eq!(u64s.inserted().len(), 3);
+            assert_eq!(u64s.modified().len(), 1);
+            assert_eq!(u64s.get(modified), Some(&6));
+            assert_eq!(u64s.len(), 6);
+        },
+    );
+}
+
+#[test]
+fn delete_many() {
+    let world = World::new::<(usize, u32, Parent, Child)>();
+    world.tight_pack::<(usize, u32)>();
+    world.track_changes::<u32>();
+
+    let (entities, root) = world
+        .run::<(EntitiesMut, &mut usize, &mut u32, &mut Parent, &mut Child), _, _>(
+            |(mut entities, mut usizes, mut u32s, mut parents, mut children)| {
+                let new_entities = entities.bulk_add_entity((&mut usizes, &mut u32s), (0..4, 0..4));
+                let root = entities.add_entity((), ());
+                (&entities, &mut parents, &mut children).attach(new_entities[1], root);
+                (&entities, &mut parents, &mut children).attach(new_entities[3], root);
+                (new_entities, root)
+            },
+        );
+
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        assert_eq!(
+            all_storages.delete_many(&[entities[0], entities[1], entities[0]]),
+            2
+        );
+        assert_eq!(all_storages.delete_many(&[entities[1]]), 0);
+    });
+
+    world.run::<(&usize, &u32, &Parent, &Child), _, _>(|(usizes, u32s, parents, children)| {
+        assert_eq!((&usizes, &u32s).iter().count(), 2);
+        assert_eq!((&usizes, &u32s).get(entities[2]), Some((&2, &2)));
+        assert_eq!((&usizes, &u32s).get(entities[3]), Some((&3, &3)));
+        assert_eq!(u32s.removed().count(), 2);
+        assert_eq!(
+            (&parents, &children).children(root).collect::<Vec<_>>(),
+            vec![entities[3]]
+        );
+    });
+
+    // without workloads removals are only forgotten manually
+    world.run::<&mut u32, _, _>(|mut u32s| {
+        u32s.clear_removed();
+        assert_eq!(u32s.removed().count(), 0);
+    });
+}
+
+#[test]
+fn clear() {
+    let world = World::new::<(usize, u32, u64)>();
+    world.tight_pack::<(usize, u32)>();
+    world.update_pack::<u64>();
+    world.track_changes::<usize>();
+    world.register_unique(0i8);
+
+    let entities = world.run::<(EntitiesMut, &mut usize, &mut u32, &mut u64), _, _>(
+        |(mut entities, mut usizes, mut u32s, mut u64s)| {
+            let mut new_entities = entities.bulk_add_entity((&mut usizes, &mut u32s), (0..3, 0..3));
+            new_entities.extend(entities.bulk_add_entity(&mut u64s, 0..3));
+            new_entities
+        },
+    );
+
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        assert!(all_storages.delete(entities[1]));
+        all_storages.clear();
+        for &entity in &entities {
+            assert!(!all_storages.delete(entity));
+        }
+    });
+
+    world.run::<(&usize, &u32, &u64, Unique<&i8>), _, _>(|(usizes, u32s, u64s, i8s)| {
+        assert!(usizes.is_empty());
+        assert!(u32s.is_empty());
+        assert!(u64s.is_empty());
+        assert_eq!(u64s.inserted().len(), 0);
+        assert_eq!(usizes.removed().count(), 3);
+        assert_eq!(*i8s, 0);
+    });
+
+    // all indices are recycled and packs still work
+    let new_entities = world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+        |(mut entities, mut usizes, mut u32s)| {
+            let new_entities = entities.bulk_add_entity((&mut usizes, &mut u32s), (0..7, 0..7));
+            assert_eq!((&usizes, &u32s).iter().count(), 7);
+            new_entities
+        },
+    );
+    for entity in &entities {
+        assert!(!new_entities.contains(entity));
+    }
+    let unique: std::collections::HashSet<_> = new_entities.iter().collect();
+    assert_eq!(unique.len(), 7);
+}
+
+#[test]
+fn hooks() {
+    use std::sync::{Arc, Mutex};
+
+    let world = World::new::<(usize, u32)>();
+    world.tight_pack::<(usize, u32)>();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let insert_log = log.clone();
+    let remove_log = log.clone();
+    let delete_log = log.clone();
+    world.set_hooks(
+        Hooks::default()
+            .on_insert(move |_, component: &mut usize| {
+                insert_log.lock().unwrap().push(("insert", *component))
+            })
+            .on_remove(move |_, component: &mut usize| {
+                remove_log.lock().unwrap().push(("remove", *component))
+            })
+            .on_delete(move |_, component: usize| {
+                delete_log.lock().unwrap().push(("delete", component))
+            }),
+    );
+
+    let (entity0, entity1) = world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+        |(mut entities, mut usizes, mut u32s)| {
+            let entity0 = entities.add_entity((&mut usizes, &mut u32s), (0, 0));
+            let entity1 = entities.add_entity((&mut usizes, &mut u32s), (1, 1));
+            entities.add_component((&mut usizes, &mut u32s), (2,), entity0);
+            (entity0, entity1)
+        },
+    );
+    world.entity_builder().with(3usize).build();
+    world.run::<(&mut usize, &mut u32), _, _>(|(mut usizes, mut u32s)| {
+        assert_eq!(
+            Remove::<(usize,)>::remove((&mut usizes, &mut u32s), entity1),
+            (Some(1),)
+        );
+    });
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        all_storages.delete(entity0);
+        all_storages.clear();
+    });
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            ("insert", 0),
+            ("insert", 1),
+            ("remove", 0),
+            ("insert", 2),
+            ("insert", 3),
+            ("remove", 1),
+            ("delete", 2),
+            ("delete", 3),
+        ]
+    );
+
+    assert_eq!(
+        world.try_set_hooks(Hooks::<i8>::default()).err(),
+        Some(shipyard::error::GetStorage::MissingComponent("i8"))
+    );
+
+    // components left when the World is dropped are handed to `on_delete` too
+    world.entity_builder().with(4usize).build();
+    drop(world);
+    assert_eq!(log.lock().unwrap().last(), Some(&("delete", 4)));
+}
+
+#[test]
+fn entities_iter() {
+    let world = World::new::<(usize,)>();
+
+    let (entity0, entity1, entity2) =
+        world.run::<(EntitiesMut, &mut usize), _, _>(|(mut entities, mut usizes)| {
+            let entity0 = entities.add_entity((), ());
+            let entity1 = entities.add_entity(&mut usizes, 0);
+            let entity2 = entities.add_entity((), ());
+            (entity0, entity1, entity2)
+        });
+
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        all_storages.delete(entity0);
+        all_storages.delete(entity2);
+    });
+
+    world.run::<Entities, _, _>(|entities| {
+        assert!(!entities.is_alive(entity0));
+        assert!(entities.is_alive(entity1));
+        assert!(!entities.is_alive(entity2));
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities.iter().collect::<Vec<_>>(), vec![entity1]);
+    });
+
+    world.run::<EntitiesMut, _, _>(|mut entities| {
+        let entity3 = entities.add_entity((), ());
+        assert!(entities.is_alive(entity3));
+        assert_eq!(entities.len(), 2);
+        assert_eq!(entities.iter().count(), 2);
+    });
+
+    world.run::<AllStorages, _, _>(|mut all_storages| all_storages.clear());
+    world.run::<Entities, _, _>(|entities| {
+        assert!(entities.is_empty());
+    });
+}
+
+#[test]
+fn reserve_entity() {
+    let world = World::new::<(usize, u32)>();
+
+    let entity0 = world.run::<EntitiesMut, _, _>(|mut entities| entities.add_entity((), ()));
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        all_storages.delete(entity0);
+    });
+
+    world.add_workload(
+        "Spawn",
+        WorkloadBuilder::default()
+            .with_fn::<(Entities, &mut usize), _>(|(entities, mut usizes)| {
+                let entity = entities.reserve_entity();
+                entities.add_component(&mut usizes, 0, entity);
+            })
+            .with_fn::<(Entities, &mut u32), _>(|(entities, mut u32s)| {
+                for entity in entities.reserve_entities(2) {
+                    entities.add_component(&mut u32s, 1, entity);
+                }
+            }),
+    );
+    let info = world.workload_info("Spawn");
+    assert_eq!(info.batches.len(), 1);
+    world.run_default();
+
+    world.run::<(Entities, &usize, &u32), _, _>(|(entities, usizes, u32s)| {
+        assert_eq!(entities.len(), 3);
+        assert!(entities
+            .iter()
+            .all(|entity| usizes.get(entity).is_some() ^ u32s.get(entity).is_some()));
+        // deleted entities aren't reused by reservations
+        assert!(entities
+            .iter()
+            .all(|entity| entity.index() != entity0.index()));
+    });
+
+    world.run::<EntitiesMut, _, _>(|mut entities| {
+        assert_eq!(entities.len(), 3);
+        let recycled = entities.add_entity((), ());
+        assert_eq!(recycled.index(), entity0.index());
+        let new = entities.add_entity((), ());
+        assert_eq!(new.index(), 4);
+    });
+}
+
+#[test]
+fn dynamic_storages() {
+    use std::alloc::Layout;
+    use std::sync::{Arc, Mutex};
+
+    let world = World::default();
+    world.register::<u32>();
+    let dropped = Arc::new(Mutex::new(Vec::new()));
+
+    let drop_log = dropped.clone();
+    let health = world.register_dynamic(
+        DynamicComponent::new("Health", Layout::new::<u64>()).with_drop(move |bytes| {
+            let mut value = [0; 8];
+            value.copy_from_slice(bytes);
+            drop_log.lock().unwrap().push(u64::from_ne_bytes(value));
+        }),
+    );
+    let marker = world.register_dynamic(DynamicComponent::new("Marker", Layout::new::<()>()));
+
+    let entity0 = world.entity_builder().build();
+    let entity1 = world.entity_builder().build();
+    let entity2 = world.entity_builder().build();
+
+    world.run::<(Entities, SharedDynamicStorages), _, _>(|(entities, storages)| {
+        assert_eq!(storages.id("Health"), Some(health));
+        assert_eq!(storages.id("Marker"), Some(marker));
+        assert_eq!(storages.id("Mana"), None);
+
+        let mut healths = storages.view_mut(health);
+        assert!(storages.try_view(health).is_err());
+        healths.add_component(&10u64.to_ne_bytes(), entity0, &entities);
+        healths.add_component(&20u64.to_ne_bytes(), entity1, &entities);
+        healths.add_component(&30u64.to_ne_bytes(), entity2, &entities);
+        // replacing drops the previous component
+        healths.add_component(&11u64.to_ne_bytes(), entity0, &entities);
+
+        let mut markers = storages.view_mut(marker);
+        markers.add_component(&[], entity1, &entities);
+        assert_eq!(markers.len(), 1);
+        assert!(markers.contains(entity1));
+    });
+
+    world.run::<(Entities, DynamicStorages), _, _>(|(entities, storages)| {
+        let mut healths = storages.view_mut(health);
+        for (_, bytes) in healths.iter_mut() {
+            bytes[0] += 1;
+        }
+        assert_eq!(healths.get(entity0), Some(&12u64.to_ne_bytes()[..]));
+        assert_eq!(healths.remove(entity2), Some(31u64.to_ne_bytes().to_vec()));
+        assert_eq!(healths.remove(entity2), None);
+        drop(healths);
+
+        let healths = storages.view(health);
+        assert_eq!(healths.name(), "Health");
+        assert_eq!(healths.layout(), Layout::new::<u64>());
+        assert_eq!(
+            healths.iter().map(|(entity, _)| entity).collect::<Vec<_>>(),
+            vec![entity0, entity1]
+        );
+
+        drop(healths);
+        assert_eq!(
+            storages.view_mut(health).try_add_component(
+                &0u64.to_ne_bytes(),
+                EntityId::new_from_pair(100, 0),
+                &entities
+            ),
+            Err(shipyard::error::AddComponent::EntityIsNotAlive)
+        );
+    });
+
+    // dynamic storages are regular sparse sets and can be iterated with other storages
+    world.run::<(EntitiesMut, &mut u32, DynamicStorages), _, _>(
+        |(mut entities, mut u32s, storages)| {
+            entities.add_component(&mut u32s, 1, entity1);
+            entities.add_entity(&mut u32s, 2);
+
+            let mut healths = storages.view_mut(health);
+            (&mut healths.view_mut(), &u32s)
+                .iter()
+                .for_each(|(health, &value)| health[0] += value as u8);
+            assert_eq!(healths.get(entity0), Some(&12u64.to_ne_bytes()[..]));
+            assert_eq!(healths.get(entity1), Some(&22u64.to_ne_bytes()[..]));
+
+            let healths = healths.view();
+            assert_eq!(
+                (&healths, &u32s)
+                    .iter()
+                    .with_id()
+                    .into_iterator()
+                    .map(|(entity, (health, &value))| (entity, health[0], value))
+                    .collect::<Vec<_>>(),
+                vec![(entity1, 22, 1)]
+            );
+        },
+    );
+
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        all_storages.delete(entity1);
+    });
+
+    world.run::<DynamicStorages, _, _>(|storages| {
+        assert_eq!(storages.view(health).len(), 1);
+        assert!(storages.view(marker).is_empty());
+    });
+
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        all_storages.clear();
+    });
+
+    world.run::<DynamicStorages, _, _>(|storages| {
+        assert!(storages.view(health).is_empty());
+    });
+
+    assert_eq!(*dropped.lock().unwrap(), vec![10, 22, 12]);
+
+    // the scheduler can't know which dynamic storages are used
+    world.add_workload(
+        "Dynamic",
+        WorkloadBuilder::default()
+            .with_fn::<DynamicStorages, _>(move |storages| {
+                storages.view_mut(health);
+            })
+            .with_fn::<DynamicStorages, _>(move |storages| {
+                storages.view_mut(health);
+            }),
+    );
+    world.add_workload(
+        "Shared",
+        WorkloadBuilder::default()
+            .with_fn::<SharedDynamicStorages, _>(move |storages| {
+                storages.view(health);
+            })
+            .with_fn::<SharedDynamicStorages, _>(move |storages| {
+                storages.view(health);
+            }),
+    );
+    assert_eq!(world.workload_info("Dynamic").batches.len(), 2);
+    assert_eq!(world.workload_info("Shared").batches.len(), 1);
+    world.run_default();
+    world.run_workload("Shared");
+
+    // components left are dropped with the World
+    let entity0 = world.entity_builder().build();
+    world.run::<(Entities, DynamicStorages), _, _>(|(entities, storages)| {
+        storages
+            .view_mut(health)
+            .add_component(&40u64.to_ne_bytes(), entity0, &entities);
+    });
+    drop(world);
+    assert_eq!(*dropped.lock().unwrap(), vec![10, 22, 12, 40]);
+}
+
+#[test]
+fn storage_infos() {
+    use std::alloc::Layout;
+
+    let world = World::default();
+    world.register_unique(0usize);
+    let marker = world.register_dynamic(DynamicComponent::new("Marker", Layout::new::<u8>()));
+    let entity = world.entity_builder().with(0u32).with(1u64).build();
+    world.entity_builder().with(2u32).build();
+    world.run::<(Entities, DynamicStorages), _, _>(|(entities, storages)| {
+        storages
+            .view_mut(marker)
+            .add_component(&[0], entity, &entities);
+    });
+
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        let infos = all_storages.storage_infos();
+        let len = |name: &str| {
+            infos
+                .iter()
+                .find(|info| info.type_name.ends_with(name))
+                .unwrap()
+                .len
+        };
+        assert_eq!(len("u32"), 2);
+        assert_eq!(len("u64"), 1);
+        assert_eq!(len("usize"), 1);
+        assert_eq!(len("Entities"), 2);
+        assert_eq!(len("DynamicStorages"), 1);
+        assert!(infos
+            .windows(2)
+            .all(|infos| infos[0].type_name <= infos[1].type_name));
+
+        all_storages.delete(entity);
+        let infos = all_storages.storage_infos();
+        assert!(infos
+            .iter()
+            .filter(|info| !info.type_name.ends_with("usize"))
+            .all(|info| info.len <= 1));
+    });
+}
@@ -37,3 +37,29 @@ fn check_roundtrip(entity_id: EntityId, expected: &str) {
     let new_entity_id: EntityId = serde_json::from_str(expected).unwrap();
     assert_eq!(entity_id, new_entity_id);
 }
+
+#[test]
+fn entity_id_binary_layout() {
+    let entity_id = EntityId::new_from_pair(5, 3);
+    let bytes = bincode::serialize(&entity_id).unwrap();
+    assert_eq!(bincode::deserialize::<EntityId>(&bytes).unwrap(), entity_id);
+
+    // the index bits store index + 1, 0 is never a valid id
+    assert!(bincode::deserialize::<EntityId>(&bincode::serialize(&0u64).unwrap()).is_err());
+    assert!(bincode::deserialize::<EntityId>(&bincode::serialize(&(1u64 << 63)).unwrap()).is_err());
+}
+
+#[cfg(not(feature = "version_32"))]
+#[test]
+fn entity_id_legacy_binary() {
+    // EntityId::new_from_pair(5, 3) saved with bincode before the version length could change
+    let bytes = [6, 0, 0, 0, 0, 0, 3, 0];
+    assert_eq!(
+        bincode::deserialize::<EntityId>(&bytes).unwrap(),
+        EntityId::new_from_pair(5, 3)
+    );
+    assert_eq!(
+        bincode::serialize(&EntityId::new_from_pair(5, 3)).unwrap(),
+        bytes
+    );
+}
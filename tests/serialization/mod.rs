@@ -1 +1,2 @@
 mod entity_id;
+mod world;
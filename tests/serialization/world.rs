@@ -0,0 +1,154 @@
+use shipyard::internal::iterators;
+use shipyard::prelude::*;
+
+#[test]
+fn world_serde() {
+    let world = World::new::<(usize, u32, i16)>();
+    world.tight_pack::<(usize, u32)>();
+    world.register_unique(7u64);
+
+    let (entity0, entity1, entity2) = world
+        .run::<(EntitiesMut, &mut usize, &mut u32, &mut i16), _, _>(
+            |(mut entities, mut usizes, mut u32s, mut i16s)| {
+                let entity0 = entities.add_entity((&mut usizes, &mut u32s), (0, 1));
+                let entity1 = entities.add_entity((&mut usizes, &mut i16s), (2, 3));
+                let entity2 = entities.add_entity((&mut usizes, &mut u32s), (4, 5));
+                (entity0, entity1, entity2)
+            },
+        );
+
+    world.run::<AllStorages, _, _>(|mut all_storages| {
+        assert!(all_storages.delete(entity0));
+    });
+
+    let json = {
+        let mut bytes = Vec::new();
+        world
+            .serialize::<(usize, u32, i16, u64), _>(&mut serde_json::Serializer::new(&mut bytes))
+            .unwrap();
+        bytes
+    };
+
+    let world = World::deserialize::<(usize, u32, i16, u64), _>(
+        &mut serde_json::Deserializer::from_slice(&json),
+    )
+    .unwrap();
+
+    world.run::<(&usize, &u32, &i16, Unique<&u64>), _, _>(|(usizes, u32s, i16s, unique)| {
+        assert_eq!(*unique, 7);
+        assert_eq!((&usizes, &u32s).get(entity0), None);
+        assert_eq!((&usizes, &i16s).get(entity1), Some((&2, &3)));
+        assert_eq!((&usizes, &u32s).get(entity2), Some((&4, &5)));
+        assert_eq!(i16s.len(), 1);
+
+        // the tight pack is still there
+        if let iterators::Iter2::Tight(mut iter) = (&usizes, &u32s).iter() {
+            assert_eq!(iter.next(), Some((&4, &5)));
+            assert_eq!(iter.next(), None);
+        } else {
+            panic!("not packed");
+        }
+    });
+
+    world.run::<(EntitiesMut, &mut usize, &mut u32), _, _>(
+        |(mut entities, mut usizes, mut u32s)| {
+            // the removed slot is reused with a bumped version
+            let entity3 = entities.add_entity((&mut usizes, &mut u32s), (7, 8));
+            assert_eq!(
+                format!("{:?}", entity3),
+                "EntityId { index: 0, version: 1 }"
+            );
+            assert_eq!((&usizes, &u32s).iter().count(), 2);
+        },
+    );
+}
+
+#[test]
+fn world_serde_missing_pack_storage() {
+    let world = World::new::<(usize, u32)>();
+    world.tight_pack::<(usize, u32)>();
+
+    let mut bytes = Vec::new();
+    assert!(world
+        .serialize::<(usize,), _>(&mut serde_json::Serializer::new(&mut bytes))
+        .is_err());
+}
+
+#[test]
+fn world_serde_inconsistent_storages() {
+    fn deserialize(json: &str) -> Result<World, serde_json::Error> {
+        World::deserialize::<(usize, u32), _>(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    // valid save the other ones are derived from
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[0,0],[1,0],[2,0]],[0,1,2],{"Tight":[["u32","usize"],1]},[]],
+            [[[0,0]],[7],{"Tight":[["u32","usize"],1]},[]]
+        ]]"#
+    )
+    .is_ok());
+
+    // the pack is longer in one storage than in the other
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[0,0],[1,0],[2,0]],[0,1,2],{"Tight":[["u32","usize"],3]},[]],
+            [[[0,0]],[7],{"Tight":[["u32","usize"],1]},[]]
+        ]]"#
+    )
+    .is_err());
+    // the other storage isn't packed
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[0,0],[1,0],[2,0]],[0,1,2],{"Tight":[["u32","usize"],1]},[]],
+            [[[0,0]],[7],"NoPack",[]]
+        ]]"#
+    )
+    .is_err());
+    // packed entities differ
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[0,0],[1,0],[2,0]],[0,1,2],{"Tight":[["u32","usize"],1]},[]],
+            [[[1,0]],[7],{"Tight":[["u32","usize"],1]},[]]
+        ]]"#
+    )
+    .is_err());
+    // same entity twice
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[0,0],[1,0],[0,0]],[0,1,2],"NoPack",[]],
+            [[],[],"NoPack",[]]
+        ]]"#
+    )
+    .is_err());
+    // entity 3 doesn't exist and entity 1 has a different version
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[3,0]],[0],"NoPack",[]],
+            [[],[],"NoPack",[]]
+        ]]"#
+    )
+    .is_err());
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[1,1]],[0],"NoPack",[]],
+            [[],[],"NoPack",[]]
+        ]]"#
+    )
+    .is_err());
+    // a loose storage doesn't have all packed entities
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[0,0],[1,0]],[0,1],{"Loose":[["usize"],["u32"],2]},[]],
+            [[[0,0]],[7],"NoPack",["usize"]]
+        ]]"#
+    )
+    .is_err());
+    assert!(deserialize(
+        r#"[[[[0,0],[1,0],[2,0]],null],[
+            [[[0,0],[1,0]],[0,1],{"Loose":[["usize"],["u32"],1]},[]],
+            [[[0,0]],[7],"NoPack",["usize"]]
+        ]]"#
+    )
+    .is_ok());
+}